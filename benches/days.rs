@@ -0,0 +1,75 @@
+//! Criterion benchmarks of the parse and solve phases of every day, on the real puzzle inputs.
+//!
+//! Run with `cargo bench`, or e.g. `cargo bench -- day15` for a single day. For a quick overview of the whole calendar,
+//! `aoc21 bench` is a lot faster.
+use std::fs;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+use aoc21::days::*;
+
+/// Reads the input relative to the repo root, so the benchmarks work from any directory. Days whose input is still
+/// embedded in the code get an empty string.
+fn load(input: Option<&str>) -> String {
+    input
+        .map(|path| fs::read_to_string(format!("{}/{}", env!("CARGO_MANIFEST_DIR"), path)).unwrap())
+        .unwrap_or_default()
+}
+
+macro_rules! bench_day {
+    ($c:expr, $name:expr, $input:expr, $module:ident) => {
+        bench_day!($c, $name, $input, $module, 100)
+    };
+    ($c:expr, $name:expr, $input:expr, $module:ident, $sample_size:expr) => {{
+        let input = load($input);
+        let parsed = $module::parse(&input);
+        let mut group = $c.benchmark_group($name);
+        group.sample_size($sample_size);
+        group.bench_function("parse", |b| b.iter(|| $module::parse(black_box(&input))));
+        group.bench_function("solve", |b| {
+            b.iter(|| $module::solve_parsed(black_box(&parsed)))
+        });
+        group.finish();
+    }};
+}
+
+fn bench_days(c: &mut Criterion) {
+    bench_day!(c, "day01", Some("input/01.txt"), day01_depth_scanning);
+    bench_day!(c, "day02", Some("input/02.txt"), day02_dive);
+    bench_day!(c, "day03", Some("input/03.txt"), day03_diagnostic);
+    bench_day!(c, "day04", Some("input/04.txt"), day04_squid_bingo);
+    bench_day!(c, "day05", Some("input/05.txt"), day05_hydro_vents);
+    bench_day!(c, "day06", Some("input/06.txt"), day06_lanternfish);
+    bench_day!(c, "day07", Some("input/07.txt"), day07_crabs);
+    bench_day!(c, "day08", Some("input/08.txt"), day08_seven_segment);
+    bench_day!(c, "day09", Some("input/09.txt"), day09_smoke_basin);
+    bench_day!(c, "day10", Some("input/10.txt"), day10_syntax_scoring);
+    bench_day!(c, "day11", Some("input/11.txt"), day11_dumbo);
+    bench_day!(c, "day12", Some("input/12.txt"), day12_passage);
+    bench_day!(c, "day13", Some("input/13.txt"), day13_origami);
+    bench_day!(c, "day14", Some("input/14.txt"), day14_polymerization);
+    bench_day!(c, "day15", Some("input/15.txt"), day15_chiton, 10);
+    bench_day!(c, "day16", Some("input/16.txt"), day16_packet_decoder);
+    bench_day!(c, "day17", Some("input/17.txt"), day17_trick_shot);
+    bench_day!(c, "day18", Some("input/18.txt"), day18_snailfish);
+    bench_day!(c, "day19", Some("input/19.txt"), day19_beacon_scanner);
+    bench_day!(c, "day20", Some("input/20.txt"), day20_trench_map, 10);
+    bench_day!(c, "day21", Some("input/21.txt"), day21_dirac_dice);
+    bench_day!(c, "day22", Some("input/22.txt"), day22_reactor_reboot);
+    bench_day!(c, "day23", Some("input/23.txt"), day23_amphipod, 10);
+    bench_day!(c, "day24", Some("input/24.txt"), day24_alu);
+    bench_day!(c, "day25", Some("input/25.txt"), day25_sea_cucumber);
+
+    // Dense random herds on a grid about 13 times the size of the puzzle input, which take over a thousand steps to stop.
+    let grid = day25_sea_cucumber::generate(500, 500, 0.5, 1);
+    let mut group = c.benchmark_group("day25_generated");
+    group.sample_size(10);
+    group.bench_function("solve", |b| {
+        b.iter(|| day25_sea_cucumber::solve_parsed(black_box(&grid)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+//! The answer to one part of a day's puzzle.
+//!
+//! Most puzzles boil down to a single (possibly very large) integer, but a few produce text instead, or, like Day 13, a
+//! grid of pixels which spells out the answer in big letters.
+use std::fmt;
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+use crate::grid::Grid;
+
+const GLYPH_ON: char = 'X';
+const GLYPH_OFF: char = ' ';
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+    /// Lit pixels which need to be read by a human (or an OCR) to get the actual answer.
+    Glyphs(Grid<bool>),
+}
+
+impl Answer {
+    /// Renders glyphs one line per row. Other answers are rendered as-is.
+    pub fn render(&self) -> String {
+        match self {
+            Answer::Int(val) => val.to_string(),
+            Answer::Text(text) => text.clone(),
+            Answer::Glyphs(pixels) => pixels.render(|&lit| if lit { GLYPH_ON } else { GLYPH_OFF }),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
+
+/// Integers serialize as numbers, text as strings, and glyphs as a list of rendered rows, so they can be eyeballed in
+/// the JSON output, too.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(val) => serializer.serialize_i64(*val),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Glyphs(_) => {
+                let rendered = self.render();
+                let rows: Vec<&str> = rendered.lines().collect();
+                let mut seq = serializer.serialize_seq(Some(rows.len()))?;
+                for row in rows {
+                    seq.serialize_element(row)?;
+                }
+                seq.end()
+            }
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(val: i64) -> Self {
+        Answer::Int(val)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(val: i32) -> Self {
+        Answer::Int(i64::from(val))
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(val: u32) -> Self {
+        Answer::Int(i64::from(val))
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(val: u64) -> Self {
+        Answer::Int(i64::try_from(val).expect("Answer does not fit in an i64."))
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(val: u128) -> Self {
+        Answer::Int(i64::try_from(val).expect("Answer does not fit in an i64."))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(val: usize) -> Self {
+        Answer::Int(i64::try_from(val).expect("Answer does not fit in an i64."))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(pixels: Grid<bool>) -> Self {
+        Answer::Glyphs(pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let as_json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+        assert_eq!(as_json(Answer::from(1234usize)), "1234");
+        assert_eq!(
+            as_json(Answer::from("Merry Christmas!")),
+            "\"Merry Christmas!\""
+        );
+
+        let pixels = Grid::parse_with(".#\n#.", |ch| ch == '#');
+        assert_eq!(as_json(Answer::from(pixels)), r#"[" X","X "]"#);
+    }
+
+    #[test]
+    fn test_render_glyphs() {
+        let pixels = Grid::parse_with("##\n.#", |ch| ch == '#');
+        assert_eq!(Answer::Glyphs(pixels).to_string(), "XX\n X");
+    }
+}
@@ -0,0 +1,81 @@
+//! Lightweight timing of every day's parse and solve phases, for `aoc21 bench`.
+//!
+//! The `benches/` criterion suite is the place for careful measurements of a single day. This is meant for a quick
+//! overview of the whole calendar, and for spotting regressions against a previously saved baseline.
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+/// Timing statistics for one phase of one day, in microseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u32,
+    /// Either "parse" or "solve".
+    pub phase: String,
+    pub samples: usize,
+    pub median_us: f64,
+    pub p95_us: f64,
+}
+
+/// Returns the value at 'quantile' (in [0, 1]) of 'samples', using the nearest-rank method.
+pub fn quantile(samples: &[Duration], quantile: f64) -> Duration {
+    assert!(!samples.is_empty(), "Need at least one sample.");
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    let rank = (quantile * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn summarize(day: u32, phase: &str, samples: &[Duration]) -> Record {
+    Record {
+        day,
+        phase: phase.to_string(),
+        samples: samples.len(),
+        median_us: quantile(samples, 0.5).as_secs_f64() * 1e6,
+        p95_us: quantile(samples, 0.95).as_secs_f64() * 1e6,
+    }
+}
+
+/// Times 'day' on 'input' up to 'max_samples' times, stopping early (after at least one sample) once 'budget' is used
+/// up, so the slow days do not hold up the whole run.
+pub fn bench_day(day: &Day, input: &str, max_samples: usize, budget: Duration) -> Vec<Record> {
+    let start = Instant::now();
+    let mut parse_samples = Vec::with_capacity(max_samples);
+    let mut solve_samples = Vec::with_capacity(max_samples);
+    while parse_samples.len() < max_samples.max(1) {
+        let times = (day.time_phases)(input);
+        parse_samples.push(times.parse);
+        solve_samples.push(times.solve);
+        if start.elapsed() > budget {
+            break;
+        }
+    }
+
+    vec![
+        summarize(day.number, "parse", &parse_samples),
+        summarize(day.number, "solve", &solve_samples),
+    ]
+}
+
+/// Finds the baseline record matching 'record', if any.
+pub fn find_baseline<'a>(baseline: &'a [Record], record: &Record) -> Option<&'a Record> {
+    baseline
+        .iter()
+        .find(|old| old.day == record.day && old.phase == record.phase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantile() {
+        let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+        assert_eq!(quantile(&samples, 0.5), Duration::from_millis(10));
+        assert_eq!(quantile(&samples, 0.95), Duration::from_millis(19));
+        assert_eq!(quantile(&samples, 1.0), Duration::from_millis(20));
+        assert_eq!(quantile(&samples[..1], 0.95), Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,46 @@
+//! 2021 AoC Day 01: Sonar Sweep
+use std::str::FromStr;
+
+use crate::answer::Answer;
+
+pub fn parse(input: &str) -> Vec<i32> {
+    input
+        .lines()
+        .map(|line| i32::from_str(line).unwrap())
+        .collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(depths: &[i32]) -> (Answer, Answer) {
+    let mut prev: i32 = 99999999;
+    let mut inc: i32 = 0;
+    let mut inc_sum: i32 = 0;
+
+    let mut nrs: i32 = 0;
+    let mut q: [i32; 3] = [0i32, 0i32, 0i32];
+    let mut prev_sum: i32 = 0;
+
+    for &cur in depths {
+        // Part 1 of the problem
+        if cur > prev {
+            inc += 1;
+        }
+        prev = cur;
+
+        // Part 2 of the problem
+        q.rotate_left(1);
+        q[2] = cur;
+        let cur_sum = q.iter().sum();
+
+        nrs += 1;
+        if nrs >= 4 && cur_sum > prev_sum {
+            inc_sum += 1;
+        }
+        prev_sum = cur_sum;
+    }
+
+    (inc.into(), inc_sum.into())
+}
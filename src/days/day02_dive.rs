@@ -0,0 +1,54 @@
+//! 2021 AoC Day 02: Dive!
+use std::str::FromStr;
+
+use crate::answer::Answer;
+
+const CMD_DOWN: &str = "down";
+const CMD_UP: &str = "up";
+const CMD_FORWARD: &str = "forward";
+
+/// Parses the (command, distance) pairs.
+pub fn parse(input: &str) -> Vec<(String, i32)> {
+    input
+        .lines()
+        .map(|line_str| {
+            let cmd_and_distance: Vec<&str> = line_str.split(' ').collect();
+            let distance = i32::from_str(cmd_and_distance[1]).unwrap();
+            (cmd_and_distance[0].to_string(), distance)
+        })
+        .collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(commands: &[(String, i32)]) -> (Answer, Answer) {
+    let mut depth_a: i32 = 0;
+    let mut depth_b: i32 = 0;
+    let mut horizontal_a: i32 = 0;
+    let mut horizontal_b: i32 = 0;
+    let mut aim: i32 = 0;
+
+    for (cmd, distance) in commands {
+        let (cmd, distance) = (cmd.as_str(), *distance);
+        if cmd == CMD_DOWN {
+            depth_a += distance;
+            aim += distance;
+        } else if cmd == CMD_UP {
+            depth_a -= distance;
+            aim -= distance;
+        } else if cmd == CMD_FORWARD {
+            horizontal_a += distance;
+            horizontal_b += distance;
+            depth_b += aim * distance;
+        } else {
+            panic!("Invalid command {:?}.", cmd);
+        }
+    }
+
+    (
+        (depth_a * horizontal_a).into(),
+        (depth_b * horizontal_b).into(),
+    )
+}
@@ -0,0 +1,113 @@
+//! 2021 AoC Day 03: Binary Diagnostic
+use crate::answer::Answer;
+
+fn filter_by_bit(codes: &[String], bit_idx: usize, most_common: bool) -> Vec<String> {
+    let n_codes = codes.len() as u32;
+    if n_codes <= 1 {
+        // We make a copy of the original in this case
+        return codes.to_vec();
+    }
+
+    let n_ones: usize = codes
+        .iter()
+        .filter(|code| code.chars().nth(bit_idx) == Some('1'))
+        .count();
+
+    let mut target: char = '0';
+    let half = (n_codes as f32 / 2.0).ceil() as usize;
+    if most_common {
+        if n_ones >= half {
+            target = '1';
+        }
+    } else if n_ones < half {
+        target = '1';
+    }
+    // println!("{}, {}/{} ones => target = {}", bit_idx, n_ones, n_codes, target);
+
+    codes
+        .iter()
+        .filter(|code| code.chars().nth(bit_idx) == Some(target))
+        .cloned()
+        .collect()
+}
+
+pub fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(codes: &[String]) -> (Answer, Answer) {
+    let mut bits: [i32; 128] = [0; 128];
+    let mut n_lines: usize = 0;
+    let mut n_digits: usize = 0;
+
+    for line_str in codes {
+        n_digits = line_str.len();
+
+        for (idx, ch) in line_str.chars().enumerate() {
+            if ch == '1' {
+                bits[idx] += 1;
+            }
+        }
+
+        n_lines += 1;
+    }
+
+    let mut bits_gamma: [i32; 128] = [0; 128];
+    let mut bits_epsilon: [i32; 128] = [0; 128];
+
+    // "Dispatch" the bits into the right numbers to solve Part 1
+    for idx in 0..n_digits {
+        if bits[idx] > (n_lines as i32 / 2i32) {
+            bits_gamma[idx] = 1;
+        } else {
+            bits_epsilon[idx] = 1;
+        }
+    }
+
+    let gamma = bin_to_dec(&bits_gamma[..n_digits]);
+    let epsilon = bin_to_dec(&bits_epsilon[..n_digits]);
+
+    // Part 2
+    let mut codes_oxygen = codes.to_vec();
+    let mut codes_co2 = codes.to_vec();
+    for bit_idx in 0..n_digits {
+        codes_oxygen = filter_by_bit(&codes_oxygen, bit_idx, true);
+        codes_co2 = filter_by_bit(&codes_co2, bit_idx, false);
+    }
+
+    let oxygen_val = bin_str_to_dec(&codes_oxygen[0]);
+    let co2_val = bin_str_to_dec(&codes_co2[0]);
+
+    ((gamma * epsilon).into(), (oxygen_val * co2_val).into())
+}
+
+fn bin_str_to_dec(bit_string: &str) -> i32 {
+    let bits: Vec<i32> = bit_string
+        .chars()
+        .map(|x| (x as i32) - ('0' as i32))
+        .collect();
+    bin_to_dec(&bits)
+}
+
+fn bin_to_dec(stuff: &[i32]) -> i32 {
+    let mut idx: i32 = (stuff.len() - 1) as i32;
+    let mut acc: i32 = 0;
+    let mut exp: i32 = 1;
+
+    loop {
+        if stuff[idx as usize] == 1 {
+            acc += exp;
+        }
+        exp *= 2;
+        idx -= 1;
+        if idx < 0 {
+            break;
+        }
+    }
+
+    acc
+}
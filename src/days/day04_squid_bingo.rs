@@ -1,10 +1,10 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+//! 2021 AoC Day 04: Giant Squid
 use std::str::FromStr;
 
-#[derive(Debug)]
-struct Board {
+use crate::answer::Answer;
+
+#[derive(Debug, Clone)]
+pub struct Board {
     values: [[u32; 5]; 5],
     seen: [[bool; 5]; 5],
 }
@@ -71,89 +71,67 @@ impl Board {
     }
 }
 
-fn day_04_squid_bingo() {
-    // let input_path = Path::new("input/04-demo.txt");
-    let input_path = Path::new("input/04.txt");
-    let mut raw_lines = Vec::new();
+/// The numbers drawn, in order, and the initial (unmarked) boards.
+pub struct Bingo {
+    draws: Vec<u32>,
+    boards: Vec<Board>,
+}
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(line_str) = line {
-                raw_lines.push(line_str);
-            }
-        }
-    }
+pub fn parse(input: &str) -> Bingo {
+    let raw_lines: Vec<&str> = input.lines().collect();
 
     let draws: Vec<u32> = raw_lines[0]
-        .split(",")
+        .split(',')
         .map(|x| u32::from_str(x).unwrap())
         .collect();
     let n_boards = (raw_lines.len() - 1) / 6;
-    println!("Will parse {} boards.", n_boards);
 
     let mut boards = Vec::new();
     for board_idx in 0..n_boards {
         let mut values = [[0u32; 5]; 5];
         let seen = [[false; 5]; 5];
-        let mut row_idx = 0;
-        for row_idx in 0..5 {
+        for (row_idx, row) in values.iter_mut().enumerate() {
             let raw_row = &raw_lines[2 + board_idx * 6 + row_idx];
             let row_vals: Vec<u32> = raw_row
-                .split(" ")
-                .map(|x| x.trim())
-                .filter(|x| x.len() > 0)
+                .split_whitespace()
                 .map(|x| u32::from_str(x).unwrap())
                 .collect();
-            // TODO(andrei): Can we do this parsing in a cleaner way?
-            for col_idx in 0..5 {
-                values[row_idx][col_idx] = row_vals[col_idx];
-            }
+            row.copy_from_slice(&row_vals[..5]);
         }
-        let mut board = Board {
-            values: values,
-            seen: seen,
-        };
-        boards.push(board);
+        boards.push(Board { values, seen });
     }
 
-    let mut won = Vec::new();
-    let mut n_won = (0 as usize);
-    let n_boards = boards.len();
-    for board in &mut boards {
-        won.push(false);
-    }
+    Bingo { draws, boards }
+}
 
-    for draw in draws {
-        for (board_idx, board) in (&mut boards).into_iter().enumerate() {
-            if board.new_draw(draw) {
-                if !won[board_idx] {
-                    println!("Board {} just won!", board_idx);
-                    if n_won == 0 {
-                        let result = board.sum_unmarked() * draw;
-                        println!("Part 1 result: {}", result);
-                    }
-                    if n_won == n_boards - 1 {
-                        println!("Last winner.");
-                        let result = board.sum_unmarked() * draw;
-                        println!("Part 2 result: {}", result);
-                    }
-
-                    won[board_idx] = true;
-                    n_won += 1;
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(bingo: &Bingo) -> (Answer, Answer) {
+    let mut boards = bingo.boards.clone();
+
+    let mut won = vec![false; boards.len()];
+    let mut n_won = 0usize;
+    let n_boards = boards.len();
+    let mut part_1_result = 0u32;
+    let mut part_2_result = 0u32;
+
+    for &draw in &bingo.draws {
+        for (board_idx, board) in boards.iter_mut().enumerate() {
+            if board.new_draw(draw) && !won[board_idx] {
+                if n_won == 0 {
+                    part_1_result = board.sum_unmarked() * draw;
+                }
+                if n_won == n_boards - 1 {
+                    part_2_result = board.sum_unmarked() * draw;
                 }
+
+                won[board_idx] = true;
+                n_won += 1;
             }
         }
     }
-}
-
-fn main() {
-    day_04_squid_bingo();
-}
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    (part_1_result.into(), part_2_result.into())
 }
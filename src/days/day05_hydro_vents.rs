@@ -0,0 +1,196 @@
+//! 2021 AoC Day 05: Hydrothermal Venture
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::cmp::max;
+use std::str::FromStr;
+
+use crate::answer::Answer;
+
+#[derive(Debug)]
+struct Seafloor {
+    width: usize,
+    height: usize,
+    // Not contiguous but good enough for this problem.
+    data: Vec<Vec<i32>>,
+}
+
+#[derive(Debug)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Debug)]
+pub struct LineSegment {
+    start: Point,
+    end: Point,
+}
+
+impl Seafloor {
+    fn new(width: usize, height: usize) -> Self {
+        let mut data = Vec::new();
+        for _ in 0..height {
+            let new_vec = vec![0; width];
+            data.push(new_vec);
+        }
+        Seafloor {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Marks the points covered by 'seg'. Diagonal segments are skipped unless 'include_diagonals' is set.
+    fn register(&mut self, seg: &LineSegment, include_diagonals: bool) {
+        if !include_diagonals && !seg.is_axis_aligned() {
+            return;
+        }
+        let pts = seg.as_point_vec();
+        for p in pts {
+            self.data[p.y as usize][p.x as usize] += 1;
+        }
+    }
+
+    fn count_gte(&self, min_val: i32) -> usize {
+        let mut count = 0usize;
+
+        for row in 0..self.height {
+            for col in 0..self.width {
+                if self.data[row][col] >= min_val {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+impl LineSegment {
+    fn from_str(spec: &str) -> Self {
+        lazy_static! {
+            static ref LINE_PARSE_RE: Regex =
+                Regex::new(r"(\d+),(\d+)\s*->\s*(\d+),(\d+)").unwrap();
+        }
+
+        let caps = LINE_PARSE_RE.captures(spec).unwrap();
+        let s_x = u32::from_str(&caps[1]).unwrap();
+        let s_y = u32::from_str(&caps[2]).unwrap();
+        let e_x = u32::from_str(&caps[3]).unwrap();
+        let e_y = u32::from_str(&caps[4]).unwrap();
+
+        LineSegment {
+            start: Point { x: s_x, y: s_y },
+            end: Point { x: e_x, y: e_y },
+        }
+    }
+
+    /// Whether the segment is horizontal or vertical (or just a single point).
+    fn is_axis_aligned(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    /// Rasterizes the segment, including both of its ends, using Bresenham's algorithm.
+    ///
+    /// The puzzle only has axis-aligned and 45 deg segments, for which this gives the exact points, but it also works
+    /// for segments at any other angle.
+    fn as_point_vec(&self) -> Vec<Point> {
+        let (mut x, mut y) = (self.start.x as i64, self.start.y as i64);
+        let (end_x, end_y) = (self.end.x as i64, self.end.y as i64);
+        let dx = (end_x - x).abs();
+        let dy = -(end_y - y).abs();
+        let step_x = (end_x - x).signum();
+        let step_y = (end_y - y).signum();
+        // Keeps track of how far off the ideal line we are, in both directions at once.
+        let mut err = dx + dy;
+
+        let mut ret = Vec::with_capacity((max(dx, -dy) + 1) as usize);
+        loop {
+            ret.push(Point {
+                x: x as u32,
+                y: y as u32,
+            });
+            if x == end_x && y == end_y {
+                break;
+            }
+            let err_2 = 2 * err;
+            if err_2 >= dy {
+                err += dy;
+                x += step_x;
+            }
+            if err_2 <= dx {
+                err += dx;
+                y += step_y;
+            }
+        }
+        ret
+    }
+}
+
+pub fn parse(input: &str) -> Vec<LineSegment> {
+    input.lines().map(LineSegment::from_str).collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(line_segments: &[LineSegment]) -> (Answer, Answer) {
+    let mut max_x: u32 = 0;
+    let mut max_y: u32 = 0;
+    for seg in line_segments {
+        max_x = max(max_x, max(seg.start.x, seg.end.x));
+        max_y = max(max_y, max(seg.start.y, seg.end.y));
+    }
+    // All coordinates are 0-based.
+    max_x += 1;
+    max_y += 1;
+
+    let count_overlaps = |include_diagonals: bool| {
+        let mut seafloor = Seafloor::new(max_x as usize, max_y as usize);
+        for seg in line_segments {
+            seafloor.register(seg, include_diagonals);
+        }
+        seafloor.count_gte(2)
+    };
+
+    (count_overlaps(false).into(), count_overlaps(true).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(spec: &str) -> Vec<(u32, u32)> {
+        LineSegment::from_str(spec)
+            .as_point_vec()
+            .iter()
+            .map(|p| (p.x, p.y))
+            .collect()
+    }
+
+    #[test]
+    fn test_axis_aligned_and_diagonal() {
+        assert!(LineSegment::from_str("0,9 -> 5,9").is_axis_aligned());
+        assert!(!LineSegment::from_str("8,0 -> 0,8").is_axis_aligned());
+        assert_eq!(points("3,4 -> 1,4"), vec![(3, 4), (2, 4), (1, 4)]);
+        assert_eq!(points("7,0 -> 7,2"), vec![(7, 0), (7, 1), (7, 2)]);
+        assert_eq!(points("9,7 -> 7,9"), vec![(9, 7), (8, 8), (7, 9)]);
+        assert_eq!(points("2,2 -> 2,2"), vec![(2, 2)]);
+    }
+
+    #[test]
+    fn test_bresenham() {
+        assert_eq!(
+            points("0,0 -> 6,2"),
+            vec![(0, 0), (1, 0), (2, 1), (3, 1), (4, 1), (5, 2), (6, 2)]
+        );
+        // Steep and reversed lines rasterize to the same points as their mirrored versions.
+        let mut steep = points("2,6 -> 0,0");
+        steep.sort();
+        let mut steep_fwd = points("0,0 -> 2,6");
+        steep_fwd.sort();
+        assert_eq!(steep, steep_fwd);
+        assert_eq!(steep.len(), 7);
+    }
+}
@@ -0,0 +1,297 @@
+//! 2021 AoC Day 06: Lanternfish
+//!
+//! Count exponentially growing fish, which only depend on how many of them are of each age.
+//!
+//! Stepping the per-age counts one day at a time is plenty for the puzzle, but it is O(days). Since a day is a linear
+//! map from the counts to the next day's counts, we can instead raise its matrix to the power of 'days' by repeated
+//! squaring, which only takes O(log(days)) matrix products. The counts grow by roughly 9% per day, so they overflow a
+//! u128 after about a thousand days; big integers take it from there.
+use std::fmt;
+use std::str::FromStr;
+
+use num_traits::{CheckedAdd, CheckedMul, One, Zero};
+
+use crate::answer::Answer;
+
+const NEW_FISH_OFFSET: usize = 2usize;
+const SPAWN_INTERVAL: usize = 6usize;
+
+/// Anything we can count fish with, e.g., u128, which reports overflows, or big integers, which never overflow.
+pub trait FishCount: Clone + Zero + One + CheckedAdd + CheckedMul + From<u64> {}
+
+impl<T: Clone + Zero + One + CheckedAdd + CheckedMul + From<u64>> FishCount for T {}
+
+/// Describes how a species of fish reproduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Species {
+    /// A fish which has just spawned starts counting down from this age again.
+    pub spawn_interval: usize,
+    /// How much longer than 'spawn_interval' newborn fish take to spawn for the first time.
+    pub new_fish_offset: usize,
+}
+
+pub const LANTERNFISH: Species = Species {
+    spawn_interval: SPAWN_INTERVAL,
+    new_fish_offset: NEW_FISH_OFFSET,
+};
+
+impl Species {
+    /// The age of newborn fish, which is the largest one any fish can have.
+    pub fn max_age(&self) -> usize {
+        self.spawn_interval + self.new_fish_offset
+    }
+
+    /// The matrix which maps the fish counts by age to the next day's counts, e.g., 9x9 for lanternfish.
+    fn transition<T: FishCount>(&self) -> Matrix<T> {
+        let mut transition = Matrix::zeros(self.max_age() + 1);
+        for age in 1..=self.max_age() {
+            transition.set(age - 1, age, T::one());
+        }
+        // Fish at age zero are reset, and each of them spawns a new fish.
+        // Without an offset, both end up in the same bucket.
+        transition.set(self.spawn_interval, 0, T::one());
+        let newborn = transition.get(self.max_age(), 0).clone() + T::one();
+        transition.set(self.max_age(), 0, newborn);
+        transition
+    }
+}
+
+/// A square matrix with checked arithmetic, so that products can report overflows instead of silently wrapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Matrix<T> {
+    size: usize,
+    cells: Vec<T>,
+}
+
+impl<T: FishCount> Matrix<T> {
+    fn zeros(size: usize) -> Self {
+        Matrix {
+            size,
+            cells: vec![T::zero(); size * size],
+        }
+    }
+
+    fn identity(size: usize) -> Self {
+        let mut identity = Matrix::zeros(size);
+        for idx in 0..size {
+            identity.set(idx, idx, T::one());
+        }
+        identity
+    }
+
+    fn get(&self, row: usize, col: usize) -> &T {
+        &self.cells[row * self.size + col]
+    }
+
+    fn set(&mut self, row: usize, col: usize, value: T) {
+        self.cells[row * self.size + col] = value;
+    }
+
+    /// Returns 'None' if any entry of the product overflows.
+    fn checked_mul(&self, other: &Matrix<T>) -> Option<Matrix<T>> {
+        let mut product = Matrix::zeros(self.size);
+        for row in 0..self.size {
+            for col in 0..self.size {
+                let mut acc = T::zero();
+                for k in 0..self.size {
+                    acc = acc.checked_add(&self.get(row, k).checked_mul(other.get(k, col))?)?;
+                }
+                product.set(row, col, acc);
+            }
+        }
+        Some(product)
+    }
+
+    /// Exponentiation by squaring: O(log(exponent)) products.
+    fn checked_pow(&self, mut exponent: u64) -> Option<Matrix<T>> {
+        let mut result = Matrix::identity(self.size);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.checked_mul(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+/// Why 'count_fish' could not count the fish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FishError {
+    /// A fish is older than the species allows.
+    InvalidAge { age: usize, max_age: usize },
+    /// The number of fish does not fit in the count type.
+    Overflow,
+}
+
+impl fmt::Display for FishError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FishError::InvalidAge { age, max_age } => {
+                write!(f, "a fish of age {} is older than {}", age, max_age)
+            }
+            FishError::Overflow => write!(f, "too many fish to count"),
+        }
+    }
+}
+
+/// Returns the number of fish after 'days', counted in a 'T'.
+pub fn count_fish<T: FishCount>(
+    initial_state: &[usize],
+    days: u64,
+    species: &Species,
+) -> Result<T, FishError> {
+    let max_age = species.max_age();
+    let mut fish_by_age = vec![0usize; max_age + 1];
+    for &fish in initial_state {
+        match fish_by_age.get_mut(fish) {
+            Some(n_fish) => *n_fish += 1,
+            None => return Err(FishError::InvalidAge { age: fish, max_age }),
+        }
+    }
+
+    // Every fish of age 'age' turns into the fish counted in column 'age' of the transition matrix after 'days'.
+    let after_days = species
+        .transition::<T>()
+        .checked_pow(days)
+        .ok_or(FishError::Overflow)?;
+    let mut total = T::zero();
+    for (age, &n_fish) in fish_by_age.iter().enumerate() {
+        if n_fish == 0 {
+            continue;
+        }
+        let mut descendants = T::zero();
+        for new_age in 0..=max_age {
+            descendants = descendants
+                .checked_add(after_days.get(new_age, age))
+                .ok_or(FishError::Overflow)?;
+        }
+        total = descendants
+            .checked_mul(&T::from(n_fish as u64))
+            .and_then(|fish| total.checked_add(&fish))
+            .ok_or(FishError::Overflow)?;
+    }
+    Ok(total)
+}
+
+/// Steps the per-age counts one day at a time, which the tests compare 'count_fish' against.
+#[cfg(test)]
+fn simulate(initial_state: &[usize], max_sim_steps: usize, species: &Species) -> usize {
+    let max_age = species.max_age();
+    let mut fish_by_age = vec![0usize; max_age + 1];
+    for &fish in initial_state {
+        fish_by_age[fish] += 1;
+    }
+
+    for _ in 0..max_sim_steps {
+        let new_fish = fish_by_age[0];
+        let new_reset = fish_by_age[0];
+
+        fish_by_age.rotate_left(1);
+        fish_by_age[max_age] = new_fish;
+        fish_by_age[species.spawn_interval] += new_reset;
+    }
+
+    fish_by_age.into_iter().sum()
+}
+
+pub fn parse(input: &str) -> Vec<usize> {
+    input
+        .trim()
+        .split(',')
+        .map(|x| usize::from_str(x).unwrap())
+        .collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(initial_state: &[usize]) -> (Answer, Answer) {
+    let n_fish_part_1: u128 = count_fish(initial_state, 80, &LANTERNFISH)
+        .unwrap_or_else(|err| panic!("Cannot count the fish: {}", err));
+    let n_fish_part_2: u128 = count_fish(initial_state, 256, &LANTERNFISH)
+        .unwrap_or_else(|err| panic!("Cannot count the fish: {}", err));
+
+    (n_fish_part_1.into(), n_fish_part_2.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use super::*;
+
+    const DEMO: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_matches_simulation() {
+        let initial_state = parse(DEMO);
+        let other_species = [
+            LANTERNFISH,
+            Species {
+                spawn_interval: 3,
+                new_fish_offset: 1,
+            },
+            Species {
+                spawn_interval: 10,
+                new_fish_offset: 0,
+            },
+        ];
+        for species in &other_species {
+            for days in [0, 1, 2, 7, 18, 80, 123] {
+                let expected = simulate(&initial_state, days, species) as u128;
+                assert_eq!(
+                    count_fish::<u128>(&initial_state, days as u64, species),
+                    Ok(expected)
+                );
+            }
+        }
+        assert_eq!(count_fish::<u128>(&initial_state, 18, &LANTERNFISH), Ok(26));
+    }
+
+    #[test]
+    fn test_invalid_age() {
+        let err = count_fish::<u128>(&[3, 9], 18, &LANTERNFISH).unwrap_err();
+        assert_eq!(err, FishError::InvalidAge { age: 9, max_age: 8 });
+        assert_eq!(err.to_string(), "a fish of age 9 is older than 8");
+        // Newborns are the oldest fish there can be.
+        assert_eq!(
+            count_fish::<u128>(&[3, 8], 18, &LANTERNFISH),
+            Ok(simulate(&[3, 8], 18, &LANTERNFISH) as u128)
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let initial_state = parse(DEMO);
+        // About as long as a u128 can keep up.
+        let u128_count = count_fish::<u128>(&initial_state, 900, &LANTERNFISH).unwrap();
+        let big_count = count_fish::<BigUint>(&initial_state, 900, &LANTERNFISH).unwrap();
+        assert_eq!(BigUint::from(u128_count), big_count);
+
+        assert_eq!(
+            count_fish::<u128>(&initial_state, 10_000, &LANTERNFISH),
+            Err(FishError::Overflow)
+        );
+        // An invalid age is reported as such, even when the count would overflow as well.
+        assert_eq!(
+            count_fish::<u128>(&[3, 9], 10_000, &LANTERNFISH),
+            Err(FishError::InvalidAge { age: 9, max_age: 8 })
+        );
+        assert_eq!(
+            count_fish::<u64>(&initial_state, 256, &LANTERNFISH),
+            Ok(26984457539)
+        );
+
+        // Big integers never overflow, and 10,000 days take a few milliseconds.
+        let huge = count_fish::<BigUint>(&initial_state, 10_000, &LANTERNFISH).unwrap();
+        let next_day = count_fish::<BigUint>(&initial_state, 10_001, &LANTERNFISH).unwrap();
+        assert!(next_day > huge);
+        assert_eq!(huge.to_string().len(), 380);
+    }
+}
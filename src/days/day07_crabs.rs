@@ -0,0 +1,92 @@
+//! 2021 AoC Day 07: The Treachery of Whales
+//!
+//! Align all the crab submarines on the same horizontal position, using as little fuel as possible.
+//!
+//! The median is optimal for Part 1, and Part 2's optimum is within half a step of the mean, but rounding the mean the
+//! "obvious" way is off by one on some inputs. Trying every position between the outermost crabs is always exact,
+//! works for any fuel cost, and is still instantaneous for a thousand crabs.
+use crate::answer::Answer;
+
+/// Part 1: every step costs 1 fuel.
+pub fn linear_cost(distance: u64) -> u64 {
+    distance
+}
+
+/// Part 2: the n-th step costs n fuel, so moving 'distance' steps costs 1 + 2 + ... + distance.
+pub fn triangular_cost(distance: u64) -> u64 {
+    distance * (distance + 1) / 2
+}
+
+/// Not part of the puzzle, but handy for checking that nothing relies on a particular cost model.
+pub fn quadratic_cost(distance: u64) -> u64 {
+    distance * distance
+}
+
+/// Returns the total fuel needed for all crabs to move to 'target'.
+pub fn total_fuel(crabs: &[i64], target: i64, cost: impl Fn(u64) -> u64) -> u64 {
+    crabs
+        .iter()
+        .map(|&crab| cost((crab - target).unsigned_abs()))
+        .sum()
+}
+
+/// Returns the (position, fuel) of the cheapest alignment, preferring the leftmost position in case of ties.
+///
+/// Moving everyone beyond the outermost crabs can only cost more (assuming the cost never decreases with distance), so
+/// it is enough to search between them.
+pub fn best_alignment(crabs: &[i64], cost: impl Fn(u64) -> u64) -> (i64, u64) {
+    let leftmost = *crabs.iter().min().expect("Need at least one crab.");
+    let rightmost = *crabs.iter().max().unwrap();
+
+    (leftmost..=rightmost)
+        .map(|target| (target, total_fuel(crabs, target, &cost)))
+        .min_by_key(|&(target, fuel)| (fuel, target))
+        .unwrap()
+}
+
+pub fn parse(input: &str) -> Vec<i64> {
+    input
+        .trim()
+        .split(',')
+        .map(|raw| raw.trim().parse().unwrap())
+        .collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(crabs: &[i64]) -> (Answer, Answer) {
+    let (_, part_1) = best_alignment(crabs, linear_cost);
+    let (_, part_2) = best_alignment(crabs, triangular_cost);
+    (part_1.into(), part_2.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_demo_alignments() {
+        let crabs = parse(DEMO);
+        assert_eq!(best_alignment(&crabs, linear_cost), (2, 37));
+        assert_eq!(total_fuel(&crabs, 10, linear_cost), 71);
+        assert_eq!(best_alignment(&crabs, triangular_cost), (5, 168));
+        assert_eq!(total_fuel(&crabs, 2, triangular_cost), 206);
+    }
+
+    #[test]
+    fn test_custom_cost() {
+        // With a quadratic cost, the best position is the one closest to the mean (4.9 on the demo).
+        let crabs = parse(DEMO);
+        let (target, fuel) = best_alignment(&crabs, quadratic_cost);
+        assert_eq!(target, 5);
+        assert_eq!(fuel, total_fuel(&crabs, 5, |dist| dist * dist));
+
+        // Closures work too, e.g., a flat fee for getting moving at all.
+        let (target, fuel) = best_alignment(&crabs, |dist| if dist == 0 { 0 } else { 1 });
+        assert_eq!((target, fuel), (2, 7));
+    }
+}
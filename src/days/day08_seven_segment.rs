@@ -1,14 +1,12 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-use std::str::FromStr;
-
+//! 2021 AoC Day 08: Seven Segment Search
 use std::collections::HashMap;
 use std::collections::HashSet;
 
+use crate::answer::Answer;
+
 const UNKNOWN: u32 = 1000;
 
-/**
+/*
     Completely unambiguous:
         - 2 chars --> 1
         - 3 chars --> 7
@@ -58,7 +56,7 @@ fn decode(input: &str, mapping: &HashMap<char, char>) -> Vec<char> {
     result
 }
 
-fn readout(code: &Vec<char>) -> u32 {
+fn readout(code: &[char]) -> u32 {
     let segment_to_number: HashMap<String, u32> = HashMap::from([
         (String::from("abcefg"), 0u32),
         (String::from("cf"), 1u32),
@@ -79,12 +77,10 @@ fn readout(code: &Vec<char>) -> u32 {
 /// Decodes the LED matching using heuristics.
 ///
 /// I wonder if we could formulate this task as a MIP and solve it with an off-the-shelf solver.
-fn part_2_decoding(input: &Vec<&str>) -> HashMap<char, char> {
+fn part_2_decoding(input: &[&str]) -> HashMap<char, char> {
     let mut code_by_length_uniq: HashMap<usize, HashSet<String>> = HashMap::new();
     for code in input {
-        let my_vec = code_by_length_uniq
-            .entry(code.len())
-            .or_insert(HashSet::new());
+        let my_vec = code_by_length_uniq.entry(code.len()).or_default();
         // I *think* the 'to_string' here is meant to make a copy.
         (*my_vec).insert(sorted_string(code));
     }
@@ -114,7 +110,7 @@ fn part_2_decoding(input: &Vec<&str>) -> HashMap<char, char> {
     if c_and_f.len() != 2 {
         panic!("");
     }
-    let mut b_and_d: Vec<char> = (&chars_in_four - &chars_in_one).into_iter().collect();
+    let b_and_d: Vec<char> = (&chars_in_four - &chars_in_one).into_iter().collect();
     if b_and_d.len() != 2 {
         panic!("");
     }
@@ -151,15 +147,13 @@ fn part_2_decoding(input: &Vec<&str>) -> HashMap<char, char> {
     // The '6' will be a superset of exactly one 5-char digit -- '5'. 2 and 3
     // both have stuff 6 doesn't. This extra character is 'e'.
     let mut e = 'X';
-    let mut chars_in_five: HashSet<char> = HashSet::new();
     for code in &code_by_length[&5usize] {
         let chars_in_current: HashSet<char> = code.chars().collect();
         let aux: Vec<char> = (&chars_in_current - &chars_in_six).into_iter().collect();
-        if aux.len() == 0 {
+        if aux.is_empty() {
             // We found the five.
             let aux_reversed: Vec<char> = (&chars_in_six - &chars_in_current).into_iter().collect();
             e = aux_reversed[0];
-            chars_in_five = chars_in_current;
         }
     }
     if e == 'X' {
@@ -180,7 +174,7 @@ fn part_2_decoding(input: &Vec<&str>) -> HashMap<char, char> {
         panic!("Could not infer zero!");
     }
     let chars_in_zero: HashSet<char> = only_zero[0].chars().collect();
-    let mut b_and_g: Vec<char> = (&chars_in_zero - &chars_in_seven)
+    let b_and_g: Vec<char> = (&chars_in_zero - &chars_in_seven)
         .into_iter()
         .filter(|x| x != &e)
         .collect();
@@ -191,29 +185,18 @@ fn part_2_decoding(input: &Vec<&str>) -> HashMap<char, char> {
     // println!("b_and_g: {:?}", b_and_g);
     // println!("b_and_d: {:?}", b_and_d);
 
-    let mut b: char = 'X';
-    let mut g: char = 'X';
-    let mut d: char = 'X';
     // Manual checks are OK for this sort of intersection
-    if b_and_g[0] == b_and_d[0] {
-        b = b_and_g[0];
-        g = b_and_g[1];
-        d = b_and_d[1];
+    let (b, g, d) = if b_and_g[0] == b_and_d[0] {
+        (b_and_g[0], b_and_g[1], b_and_d[1])
     } else if b_and_g[0] == b_and_d[1] {
-        b = b_and_g[0];
-        g = b_and_g[1];
-        d = b_and_d[0];
+        (b_and_g[0], b_and_g[1], b_and_d[0])
     } else if b_and_g[1] == b_and_d[0] {
-        b = b_and_g[1];
-        g = b_and_g[0];
-        d = b_and_d[1];
+        (b_and_g[1], b_and_g[0], b_and_d[1])
     } else if b_and_g[1] == b_and_d[1] {
-        b = b_and_g[1];
-        g = b_and_g[0];
-        d = b_and_d[0];
+        (b_and_g[1], b_and_g[0], b_and_d[0])
     } else {
         panic!("b-and-g check failed");
-    }
+    };
 
     HashMap::from([
         (a, 'a'),
@@ -226,68 +209,64 @@ fn part_2_decoding(input: &Vec<&str>) -> HashMap<char, char> {
     ])
 }
 
-fn day_08_seven_segment() {
-    // let input_path = Path::new("input/08-demo.txt");
-    let input_path = Path::new("input/08.txt");
-    let mut part_1_total: u32 = 0;
-    let mut part_2_total: u32 = 0;
+/// A display's ten unique signal patterns, and its four output digits.
+pub struct Entry {
+    patterns: Vec<String>,
+    outputs: Vec<String>,
+}
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(line_str) = line {
-                let in_and_out: Vec<&str> = line_str.split(" | ").collect();
-                // println!("{:?}", in_and_out[1]);
-
-                let in_strings: Vec<&str> = in_and_out[0].split(" ").collect();
-                let out_strings: Vec<&str> = in_and_out[1].split(" ").collect();
-
-                let mut all_raw_codes = in_strings.to_vec();
-                all_raw_codes.extend(out_strings.to_vec());
-
-                let res: Vec<u32> = out_strings
-                    .iter()
-                    .copied()
-                    .map(string_to_digit_easy)
-                    .filter(|x| x != &UNKNOWN)
-                    .collect();
-                part_1_total += res.len() as u32;
-
-                let char_mapping = part_2_decoding(&all_raw_codes);
-                let out_decoded: Vec<Vec<char>> = out_strings
-                    .to_vec()
-                    .iter()
-                    .map(|x| decode(&x, &char_mapping))
-                    .collect();
-
-                let out_numbers: Vec<u32> =
-                    out_decoded.to_vec().iter().map(|x| readout(&x)).collect();
-
-                // println!("{:?}", char_mapping);
-                // println!("{:?}", out_decoded);
-                // println!("{:?}", out_numbers);
-
-                let output = out_numbers[0] * 1000
-                    + out_numbers[1] * 100
-                    + out_numbers[2] * 10
-                    + out_numbers[3];
-                // println!("{:?}", output);
-                part_2_total += output;
+pub fn parse(input: &str) -> Vec<Entry> {
+    input
+        .lines()
+        .map(|line_str| {
+            let in_and_out: Vec<&str> = line_str.split(" | ").collect();
+            Entry {
+                patterns: in_and_out[0].split(' ').map(str::to_string).collect(),
+                outputs: in_and_out[1].split(' ').map(str::to_string).collect(),
             }
-        }
-    }
-
-    println!("Part 1 result: {}", part_1_total);
-    println!("Part 2 result: {}", part_2_total);
+        })
+        .collect()
 }
 
-fn main() {
-    day_08_seven_segment();
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
 }
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+pub fn solve_parsed(entries: &[Entry]) -> (Answer, Answer) {
+    let mut part_1_total: u32 = 0;
+    let mut part_2_total: u32 = 0;
+
+    for entry in entries {
+        let in_strings: Vec<&str> = entry.patterns.iter().map(String::as_str).collect();
+        let out_strings: Vec<&str> = entry.outputs.iter().map(String::as_str).collect();
+
+        let mut all_raw_codes = in_strings.to_vec();
+        all_raw_codes.extend(out_strings.to_vec());
+
+        let res: Vec<u32> = out_strings
+            .iter()
+            .copied()
+            .map(string_to_digit_easy)
+            .filter(|x| x != &UNKNOWN)
+            .collect();
+        part_1_total += res.len() as u32;
+
+        let char_mapping = part_2_decoding(&all_raw_codes);
+        let out_decoded: Vec<Vec<char>> = out_strings
+            .iter()
+            .map(|x| decode(x, &char_mapping))
+            .collect();
+
+        let out_numbers: Vec<u32> = out_decoded.iter().map(|x| readout(x)).collect();
+
+        // println!("{:?}", char_mapping);
+        // println!("{:?}", out_decoded);
+        // println!("{:?}", out_numbers);
+
+        let output =
+            out_numbers[0] * 1000 + out_numbers[1] * 100 + out_numbers[2] * 10 + out_numbers[3];
+        part_2_total += output;
+    }
+
+    (part_1_total.into(), part_2_total.into())
 }
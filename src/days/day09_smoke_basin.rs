@@ -0,0 +1,81 @@
+//! 2021 AoC Day 09: Smoke Basin
+use crate::answer::Answer;
+use crate::grid::{Grid, Pos};
+
+fn is_min(height: &Grid<u32>, pos: Pos) -> bool {
+    let cur = height[pos];
+    height.neighbors4(pos).all(|n_pos| height[n_pos] > cur)
+}
+
+fn find_low_points(height: &Grid<u32>) -> Vec<Pos> {
+    height
+        .positions()
+        .filter(|&pos| is_min(height, pos))
+        .collect()
+}
+
+/// Return a vector of basin sizes
+fn find_basins(original_height: &Grid<u32>, cliff_id: u32) -> Vec<usize> {
+    // Operate on a mutable copy where we can scribble where we've been.
+    let mut height = original_height.clone();
+    // The cliff values are meant to be the highest available.
+    let marker = cliff_id + 1;
+    let mut basin_sizes = Vec::new();
+
+    for pos in height.positions() {
+        if height[pos] == marker || height[pos] == cliff_id {
+            continue;
+        }
+
+        // We're on a regular tile - time to search for a basin. BFS time!
+        let mut queue: Vec<Pos> = Vec::new();
+        let mut size: usize = 1;
+        queue.push(pos);
+        height[pos] = marker;
+
+        while let Some(cur) = queue.pop() {
+            for n_pos in height.neighbors4(cur) {
+                let n_height = height[n_pos];
+                if n_height != cliff_id && n_height != marker {
+                    queue.push(n_pos);
+                    size += 1;
+                    // Scribble the tile as visited.
+                    height[n_pos] = marker;
+                }
+            }
+        }
+
+        basin_sizes.push(size);
+    }
+
+    basin_sizes
+}
+
+pub fn parse(input: &str) -> Grid<u32> {
+    Grid::parse_digits(input)
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(height: &Grid<u32>) -> (Answer, Answer) {
+    let low_point_coords = find_low_points(height);
+    let mut sum = 0u32;
+    for pos in low_point_coords {
+        let risk = 1u32 + height[pos];
+        sum += risk;
+    }
+
+    let mut basin_sizes = find_basins(height, 9u32);
+    basin_sizes.sort_by(|a, b| b.cmp(a));
+    if basin_sizes.len() < 3 {
+        panic!(
+            "Insufficient basins found! Need at least 3 but found {}.",
+            basin_sizes.len()
+        );
+    }
+    let basin_score = basin_sizes[0] * basin_sizes[1] * basin_sizes[2];
+
+    (sum.into(), basin_score.into())
+}
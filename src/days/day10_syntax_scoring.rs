@@ -1,5 +1,7 @@
+//! 2021 AoC Day 10: Syntax Scoring
 use std::collections::HashMap;
-use std::fs;
+
+use crate::answer::Answer;
 
 fn get_char_to_type() -> HashMap<char, (char, bool)> {
     HashMap::from([
@@ -43,9 +45,15 @@ fn parse_line(line: &str) -> (Vec<usize>, Vec<char>) {
     (error_positions, char_queue)
 }
 
-fn day_10_syntax_scoring() {
-    // let data = fs::read_to_string("input/10-demo.txt").expect("Unable to read file.");
-    let data = fs::read_to_string("input/10.txt").expect("Unable to read file.");
+pub fn parse(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(lines: &[String]) -> (Answer, Answer) {
     let mut syntax_error_score: u32 = 0;
     let mut auto_complete_scores: Vec<u64> = Vec::new();
     let char_to_type = get_char_to_type();
@@ -55,10 +63,10 @@ fn day_10_syntax_scoring() {
     let marker_to_fix_cost: HashMap<char, u64> =
         HashMap::from([('(', 1), ('[', 2), ('{', 3), ('<', 4)]);
 
-    for line in data.split("\n") {
+    for line in lines {
         // println!("Line: {}", line);
         let (error_positions, mut pending_queue) = parse_line(line);
-        if error_positions.len() > 0 {
+        if !error_positions.is_empty() {
             // Line with syntax errors!
             let raw_char = &line.chars().nth(error_positions[0usize]).unwrap();
             let char_type = char_to_type[raw_char].0;
@@ -80,16 +88,9 @@ fn day_10_syntax_scoring() {
         }
     }
 
-    println!("Part 1 syntax error score:  {}", syntax_error_score);
-
     // Find the middle auto-complete score as the answer to Part 2.
-    auto_complete_scores.sort();
-    println!(
-        "Part 2 auto-complete score: {}",
-        auto_complete_scores[auto_complete_scores.len() / 2usize]
-    );
-}
+    auto_complete_scores.sort_unstable();
+    let middle_score = auto_complete_scores[auto_complete_scores.len() / 2usize];
 
-fn main() {
-    day_10_syntax_scoring();
+    (syntax_error_score.into(), middle_score.into())
 }
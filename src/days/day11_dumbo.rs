@@ -0,0 +1,84 @@
+//! 2021 AoC Day 11: Dumbo Octopus
+use crate::answer::Answer;
+use crate::grid::Grid;
+
+/// Returns the number of flashes that occur after n_steps given the initial condition.
+///
+/// Stops early if all octopi flash at once, also returning the (1-based) step at which this mega-flash happened.
+fn simulate_octopi(initial_octopi: &Grid<u32>, n_steps: u32) -> (u32, Option<u32>) {
+    let mut octopi = initial_octopi.clone();
+    let mut flashed = initial_octopi.map(|_| false);
+    let mut total_flashes: u32 = 0;
+    let max_energy: u32 = 9;
+
+    for step in 0..n_steps {
+        // Base increase
+        octopi.iter_mut().for_each(|energy| *energy += 1);
+        flashed
+            .iter_mut()
+            .for_each(|has_flashed| *has_flashed = false);
+
+        let mut flashes_this_step = 0u32;
+
+        // Flashing
+        loop {
+            let mut new_flashes: u32 = 0;
+            for pos in octopi.positions() {
+                if flashed[pos] || octopi[pos] <= max_energy {
+                    continue;
+                }
+
+                new_flashes += 1;
+                flashed[pos] = true;
+                for n_pos in octopi.neighbors8(pos) {
+                    octopi[n_pos] += 1;
+                }
+            }
+
+            flashes_this_step += new_flashes;
+
+            // Loop until stable
+            if new_flashes == 0u32 {
+                break;
+            }
+        }
+
+        total_flashes += flashes_this_step;
+
+        // Reset
+        for energy in octopi.iter_mut() {
+            if *energy > max_energy {
+                *energy = 0u32;
+            }
+        }
+
+        if flashes_this_step as usize == octopi.rows() * octopi.cols() {
+            return (total_flashes, Some(step + 1));
+        }
+    }
+
+    (total_flashes, None)
+}
+
+pub fn parse(input: &str) -> Grid<u32> {
+    Grid::parse_digits(input)
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(octo: &Grid<u32>) -> (Answer, Answer) {
+    let n_steps_part_1: u32 = 100;
+    let n_steps_part_2: u32 = 100000;
+
+    let (n_flashes, _) = simulate_octopi(octo, n_steps_part_1);
+    let (_, mega_flash_step) = simulate_octopi(octo, n_steps_part_2);
+
+    (
+        n_flashes.into(),
+        mega_flash_step
+            .expect("The octopi never flashed all at once.")
+            .into(),
+    )
+}
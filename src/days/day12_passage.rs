@@ -0,0 +1,282 @@
+//! 2021 AoC Day 12: Passage Pathing
+//!
+//! Not going to lie, I am both proud and surprised I was able to code this path enumeration without looking up any
+//! undergraduate graph theory.
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+
+const START_NODE: &str = "start";
+const END_NODE: &str = "end";
+
+/// Index of a cave in 'Graph::nodes'.
+type NodeId = usize;
+
+#[derive(Debug)]
+struct Node {
+    large: bool,
+    name: String,
+    neighbors: Vec<NodeId>,
+    /// Bit identifying this cave in a visited set. Only small caves get one, since large caves can always be visited.
+    small_bit: u64,
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    nodes: Vec<Node>,
+    start: NodeId,
+    end: NodeId,
+}
+
+/// The rules deciding which small caves a path may (re)visit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisitPolicy {
+    /// Part 1: small caves can be visited at most once.
+    SmallOnce,
+    /// Part 2: a single small cave other than the start can be visited twice, the rest at most once.
+    OneSmallTwice,
+}
+
+/// Everything that matters about a partial path for counting its completions: where it is, which small caves it went
+/// through, and whether it already used up its double visit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct PathState {
+    at: NodeId,
+    visited: u64,
+    used_double: bool,
+}
+
+impl VisitPolicy {
+    /// Returns the state after moving to 'node', or 'None' if the policy does not allow it.
+    fn visit(&self, graph: &Graph, state: &PathState, node: NodeId) -> Option<PathState> {
+        let cave = &graph.nodes[node];
+        let mut next = PathState {
+            at: node,
+            visited: state.visited | cave.small_bit,
+            used_double: state.used_double,
+        };
+        if cave.large || state.visited & cave.small_bit == 0 {
+            return Some(next);
+        }
+
+        match self {
+            VisitPolicy::SmallOnce => None,
+            VisitPolicy::OneSmallTwice => {
+                if node == graph.start || state.used_double {
+                    None
+                } else {
+                    next.used_double = true;
+                    Some(next)
+                }
+            }
+        }
+    }
+}
+
+/// Counts the paths from 'state' to the end, memoising the counts, since many different paths end up in the same state.
+fn count_paths_from(
+    graph: &Graph,
+    policy: VisitPolicy,
+    state: PathState,
+    cache: &mut HashMap<PathState, usize>,
+) -> usize {
+    if state.at == graph.end {
+        return 1;
+    }
+    if let Some(&count) = cache.get(&state) {
+        return count;
+    }
+
+    let mut count = 0usize;
+    for &neigh in &graph.nodes[state.at].neighbors {
+        if let Some(next) = policy.visit(graph, &state, neigh) {
+            count += count_paths_from(graph, policy, next, cache);
+        }
+    }
+
+    cache.insert(state, count);
+    count
+}
+
+/// Counts the paths from start to end which follow the given visit policy.
+pub fn count_paths(graph: &Graph, policy: VisitPolicy) -> usize {
+    let start = PathState {
+        at: graph.start,
+        visited: graph.nodes[graph.start].small_bit,
+        used_double: false,
+    };
+    count_paths_from(graph, policy, start, &mut HashMap::new())
+}
+
+pub fn parse(data: &str) -> Graph {
+    let mut nodes: Vec<Node> = Vec::new();
+    let mut ids: HashMap<String, NodeId> = HashMap::new();
+    let mut n_small = 0usize;
+
+    for row in data.lines() {
+        let start_end: Vec<String> = row.split('-').map(|x| x.to_string()).collect();
+        let mut edge = [0 as NodeId; 2];
+
+        for (name, id) in start_end.iter().zip(edge.iter_mut()) {
+            *id = *ids.entry(name.to_string()).or_insert_with(|| {
+                let is_large: bool = &name.to_ascii_uppercase() == name;
+                let small_bit = if is_large {
+                    0
+                } else {
+                    n_small += 1;
+                    assert!(n_small <= 64, "Too many small caves for a u64 visited set.");
+                    1u64 << (n_small - 1)
+                };
+                nodes.push(Node {
+                    large: is_large,
+                    name: name.to_string(),
+                    neighbors: Vec::new(),
+                    small_bit,
+                });
+                nodes.len() - 1
+            });
+        }
+
+        nodes[edge[0]].neighbors.push(edge[1]);
+        nodes[edge[1]].neighbors.push(edge[0]);
+    }
+
+    let start = *ids
+        .get(START_NODE)
+        .expect("Graph must contain a start node!");
+    let end = *ids.get(END_NODE).expect("Graph must contain an end node!");
+    for node in &nodes {
+        assert!(
+            !(node.large && node.neighbors.iter().any(|&neigh| nodes[neigh].large)),
+            "Two adjacent large caves ({}) would allow infinitely many paths.",
+            node.name
+        );
+    }
+
+    Graph { nodes, start, end }
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    // Timings on 'input/12-demo-02.txt':
+    // v0, Release mode:         70ms
+    // v0, Debug mode:          700ms
+    //
+    // Timings on 'input/12-demo-03.txt':
+    // v0.0, Release mode:     1760ms
+    //
+    // v1 (integer-indexed graph, bitmask visited sets, memoised counting), both parts, Release mode:
+    // 'input/12-demo-03.txt':   0.15ms
+    // 'input/12.txt':           0.5ms
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(caves: &Graph) -> (Answer, Answer) {
+    (
+        count_paths(caves, VisitPolicy::SmallOnce).into(),
+        count_paths(caves, VisitPolicy::OneSmallTwice).into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(name: &str) -> Graph {
+        let path = format!("{}/input/{}", env!("CARGO_MANIFEST_DIR"), name);
+        parse(&std::fs::read_to_string(path).unwrap())
+    }
+
+    /// Lists every path one by one, tracking the visits of each small cave separately, rather than with bitmasks.
+    fn naive_paths(graph: &Graph, policy: VisitPolicy, path: &mut Vec<NodeId>) -> usize {
+        let at = *path.last().unwrap();
+        if at == graph.end {
+            return 1;
+        }
+
+        let mut count = 0;
+        for &neigh in &graph.nodes[at].neighbors {
+            let cave = &graph.nodes[neigh];
+            let visits = path.iter().filter(|&&node| node == neigh).count();
+            let any_double = path.iter().any(|&node| {
+                !graph.nodes[node].large && path.iter().filter(|&&other| other == node).count() > 1
+            });
+            let allowed = cave.large
+                || visits == 0
+                || (policy == VisitPolicy::OneSmallTwice
+                    && neigh != graph.start
+                    && visits == 1
+                    && !any_double);
+            if allowed {
+                path.push(neigh);
+                count += naive_paths(graph, policy, path);
+                path.pop();
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_demos() {
+        for (name, small_once, one_small_twice) in [
+            ("12-demo-01.txt", 10, 36),
+            ("12-demo-02.txt", 19, 103),
+            ("12-demo-03.txt", 226, 3509),
+        ] {
+            let graph = load(name);
+            assert_eq!(count_paths(&graph, VisitPolicy::SmallOnce), small_once);
+            assert_eq!(
+                count_paths(&graph, VisitPolicy::OneSmallTwice),
+                one_small_twice
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_naive() {
+        // Both small caves lead to the same big cave, so the same position is reached with different visited sets, and
+        // the memoised counts are only right if they are keyed by those sets too.
+        let shared_big_cave = parse("start-a\nstart-b\na-B\nb-B\nB-end");
+        assert_eq!(count_paths(&shared_big_cave, VisitPolicy::SmallOnce), 4);
+
+        for graph in [
+            shared_big_cave,
+            load("12-demo-01.txt"),
+            load("12-demo-02.txt"),
+        ] {
+            for policy in [VisitPolicy::SmallOnce, VisitPolicy::OneSmallTwice] {
+                assert_eq!(
+                    count_paths(&graph, policy),
+                    naive_paths(&graph, policy, &mut vec![graph.start]),
+                    "{:?}",
+                    policy
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_visit_policy() {
+        let graph = parse("start-a\na-end");
+        let a = graph
+            .nodes
+            .iter()
+            .position(|node| node.name == "a")
+            .unwrap();
+        let fresh = PathState {
+            at: graph.start,
+            visited: graph.nodes[graph.start].small_bit,
+            used_double: false,
+        };
+        let in_a = VisitPolicy::SmallOnce.visit(&graph, &fresh, a).unwrap();
+        assert_eq!(VisitPolicy::SmallOnce.visit(&graph, &in_a, a), None);
+
+        // The double visit can only be used once, and never on the start.
+        let twice = VisitPolicy::OneSmallTwice.visit(&graph, &in_a, a).unwrap();
+        assert!(twice.used_double);
+        assert_eq!(VisitPolicy::OneSmallTwice.visit(&graph, &twice, a), None);
+        assert_eq!(
+            VisitPolicy::OneSmallTwice.visit(&graph, &in_a, graph.start),
+            None
+        );
+    }
+}
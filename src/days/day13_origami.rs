@@ -1,28 +1,23 @@
+//! 2021 AoC Day 13: Transparent Origami
+//!
+//! Transparent paper folding - start with a big sheet with random-looking sparse dots, and keep folding along specified
+//! lines until you are through and a specific pattern of big letters emerges.
 use std::collections::HashSet;
-/// 2021 AoC Day 13
-///
-/// Transparent paper folding - start with a big sheet with random-looking sparse dots, and keep folding along specified
-/// lines until you are through and a specific pattern of big letters emerges.
-use std::fs;
 use std::str::FromStr;
 
+use crate::answer::Answer;
+use crate::grid::Grid;
+
 #[derive(Debug)]
-struct Fold {
+pub struct Fold {
     dim: String,
     idx: usize,
 }
 
-#[derive(Debug)]
-struct Paper {
-    width: usize,
-    height: usize,
-    dots: HashSet<(usize, usize)>,
-}
-
 fn parse_dots(dot_specs: &[&str]) -> HashSet<(usize, usize)> {
     let mut dots = HashSet::new();
     for spec in dot_specs {
-        let parts: Vec<&str> = spec.split(",").collect();
+        let parts: Vec<&str> = spec.split(',').collect();
         let xx = usize::from_str(parts[0]).expect("dot spec int parse failed");
         let yy = usize::from_str(parts[1]).expect("dot spec int parse failed");
         dots.insert((xx, yy));
@@ -33,8 +28,8 @@ fn parse_dots(dot_specs: &[&str]) -> HashSet<(usize, usize)> {
 fn parse_folds(fold_specs: &[&str]) -> Vec<Fold> {
     let mut folds = Vec::new();
     for fold_spec in fold_specs {
-        let parts: Vec<&str> = fold_spec.split(" ").collect::<Vec<&str>>()[2usize]
-            .split("=")
+        let parts: Vec<&str> = fold_spec.split(' ').collect::<Vec<&str>>()[2usize]
+            .split('=')
             .collect();
         let lhs = parts[0];
         let rhs = parts[1];
@@ -49,7 +44,7 @@ fn parse_folds(fold_specs: &[&str]) -> Vec<Fold> {
 }
 
 fn fold_dot(dot: &(usize, usize), fold: &Fold) -> (usize, usize) {
-    let (mut xx, mut yy) = dot;
+    let (mut xx, mut yy) = *dot;
     if fold.dim == "x" {
         if xx > fold.idx {
             xx = fold.idx - (xx - fold.idx);
@@ -70,33 +65,43 @@ fn fold_dots(dots: &HashSet<(usize, usize)>, fold: &Fold) -> HashSet<(usize, usi
     dots.iter().map(|dot| fold_dot(dot, fold)).collect()
 }
 
-fn day_13_origami() {
-    let data = fs::read_to_string("input/13.txt").expect("Unable to read file.");
-    // let data = fs::read_to_string("input/13-demo.txt").expect("Unable to read file.");
-    let in_lines: Vec<&str> = data.split("\n").collect();
+/// The initial dots on the transparent paper, and the folds to apply to it.
+pub struct Manual {
+    dots: HashSet<(usize, usize)>,
+    folds: Vec<Fold>,
+}
 
-    let blank_line = in_lines.iter().position(|l| l.len() == 0).expect("yep");
-    println!("{:?}", blank_line);
+pub fn parse(input: &str) -> Manual {
+    let in_lines: Vec<&str> = input.lines().collect();
+
+    let blank_line = in_lines
+        .iter()
+        .position(|l| l.is_empty())
+        .expect("Missing blank line between the dots and the folds.");
 
     let dot_lines = &in_lines[..blank_line];
     let fold_instruction_lines = &in_lines[(blank_line + 1)..];
 
-    let dots: HashSet<(usize, usize)> = parse_dots(dot_lines);
+    Manual {
+        dots: parse_dots(dot_lines),
+        folds: parse_folds(fold_instruction_lines),
+    }
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(manual: &Manual) -> (Answer, Answer) {
+    let Manual { dots, folds } = manual;
     let mut width = dots.iter().map(|dot| dot.1).max().unwrap();
     let mut height = dots.iter().map(|dot| dot.0).max().unwrap();
 
-    let folds: Vec<Fold> = parse_folds(fold_instruction_lines);
-    // println!("{:?}", dots);
-    // println!("{:?}", folds);
-
-    let part_1_board = fold_dots(&(dots.clone()), &folds[0]);
+    let part_1_board = fold_dots(dots, &folds[0]);
     let part_1_answer = part_1_board.len();
-    println!("Part 1: {:?} dots after the first fold", part_1_answer);
 
-    println!("Part 2:");
-    println!("Before folding:   width={} height={}", width, height);
     let mut part_2_board = dots.clone();
-    for fold in &folds {
+    for fold in folds {
         part_2_board = fold_dots(&part_2_board, fold);
 
         if fold.dim == "y" {
@@ -106,23 +111,11 @@ fn day_13_origami() {
             width = fold.idx;
         }
     }
-    println!("After folding:    width={} height={}", width, height);
 
-    let mut cart_dots: Vec<Vec<char>> = Vec::new();
-    for _ in 0..height {
-        cart_dots.push(vec![' '; width]);
-    }
+    let mut final_code = Grid::new(height, width, false);
     for dot in part_2_board {
-        cart_dots[dot.1][dot.0] = 'X';
-    }
-
-    println!("Final code:");
-    for row in &cart_dots {
-        let row_str: String = row.iter().copied().collect();
-        println!("{}", &row_str);
+        final_code[(dot.1, dot.0)] = true;
     }
-}
 
-fn main() {
-    day_13_origami();
+    (part_1_answer.into(), final_code.into())
 }
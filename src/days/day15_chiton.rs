@@ -0,0 +1,118 @@
+//! 2021 AoC Day 15: Chiton
+//!
+//! Basically just Dijkstra's on a grid.
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::answer::Answer;
+use crate::grid::{Grid, Pos};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Candidate {
+    cost: u32,
+    prev: Pos,
+}
+
+/// Based on the example from the rust book, adapted to work with grid graphs.
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse order so we create a min-heap - we always want to pop the candidate with the smallest cost first.
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.prev.0.cmp(&other.prev.0))
+            .then_with(|| self.prev.1.cmp(&other.prev.1))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Finds the lowest-cost path from 'start' to 'end' using Dijkstra's algorithm.
+///
+/// The graph is a lattice defined by 'grid' using a 4-neighbor connectivity pattern.
+fn get_path(start: Pos, end: Pos, grid: &Grid<u32>) -> Vec<Pos> {
+    let max_cost: u32 = u32::MAX;
+    let undefined: Pos = (usize::MAX, usize::MAX);
+
+    let mut cost = Grid::new(grid.rows(), grid.cols(), max_cost);
+    let mut prev = Grid::new(grid.rows(), grid.cols(), undefined);
+    let mut queue = BinaryHeap::new();
+
+    queue.push(Candidate {
+        cost: 0,
+        prev: start,
+    });
+    cost[start] = 0;
+
+    while let Some(candidate) = queue.pop() {
+        for neighbor in grid.neighbors4(candidate.prev) {
+            let next = Candidate {
+                cost: candidate.cost + grid[neighbor],
+                prev: neighbor,
+            };
+
+            if next.cost < cost[neighbor] {
+                queue.push(next);
+                cost[neighbor] = next.cost;
+                prev[neighbor] = candidate.prev;
+            }
+        }
+    }
+
+    let mut result_path: Vec<Pos> = Vec::new();
+    let mut cur = end;
+    while cur != undefined {
+        result_path.push(cur);
+        cur = prev[cur];
+    }
+
+    result_path
+}
+
+/// Computes the cost of 'path' over 'grid', not including the start position.
+///
+/// Decoupled from the main Dijkstra for simplicity.
+fn cost(path: &[Pos], grid: &Grid<u32>) -> u32 {
+    path.iter().rev().map(|&pos| grid[pos]).skip(1).sum()
+}
+
+/// A version of modulo that is 1-based - wraps numbers into the range [1..max[
+fn wrap(x: u32, max: u32) -> u32 {
+    (x - 1) % (max - 1) + 1
+}
+
+/// Returns 'grid' tiled 'factor' times in X and in Y, with increasing values in more distant clones.
+fn enlarge_grid(grid: &Grid<u32>, factor: usize) -> Grid<u32> {
+    grid.tile(factor, |&original_x, chunk_r, chunk_c| {
+        let offset = (chunk_r + chunk_c) as u32;
+        wrap(original_x + offset, 10u32)
+    })
+}
+
+pub fn parse(input: &str) -> Grid<u32> {
+    Grid::parse_digits(input)
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(grid: &Grid<u32>) -> (Answer, Answer) {
+    let end = (grid.rows() - 1, grid.cols() - 1);
+    let path = get_path((0usize, 0usize), end, grid);
+
+    // This was bugged because my cost was evaluating the path in the wrong direction, so it was dropping the part of
+    // the cost resulting from 'end', when it should have been dropping it for 'start'.
+    let part_1_cost = cost(&path, grid);
+
+    let big_grid = enlarge_grid(grid, 5usize);
+    let big_end = (big_grid.rows() - 1, big_grid.cols() - 1);
+    let big_path = get_path((0usize, 0usize), big_end, &big_grid);
+    let part_2_cost = cost(&big_path, &big_grid);
+
+    (part_1_cost.into(), part_2_cost.into())
+}
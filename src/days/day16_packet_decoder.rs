@@ -0,0 +1,979 @@
+//! 2021 AoC Day 16: Packet Decoder
+//!
+//! Decoding packets from bits and evaluating operator trees, as well as encoding them back, so that we can generate our
+//! own transmissions.
+use std::fmt;
+
+use crate::answer::Answer;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum OperatorType {
+    SUM,
+    PROD,
+    MIN,
+    MAX,
+    GT,
+    LT,
+    EQ,
+}
+
+const SUM_ID: usize = 0;
+const PROD_ID: usize = 1;
+const MIN_ID: usize = 2;
+const MAX_ID: usize = 3;
+const LITERAL_ID: usize = 4usize;
+const GT_ID: usize = 5;
+const LT_ID: usize = 6;
+const EQ_ID: usize = 7;
+
+const VERSION_BITS: usize = 3;
+const TYPE_ID_BITS: usize = 3;
+/// Literals are split into groups of 4 bits, each prefixed by a bit saying whether more groups follow.
+const LITERAL_GROUP_BITS: usize = 4;
+const TOTAL_BITS_LENGTH_BITS: usize = 15;
+const N_PACKETS_LENGTH_BITS: usize = 11;
+
+impl OperatorType {
+    fn type_id(&self) -> usize {
+        match self {
+            OperatorType::SUM => SUM_ID,
+            OperatorType::PROD => PROD_ID,
+            OperatorType::MIN => MIN_ID,
+            OperatorType::MAX => MAX_ID,
+            OperatorType::GT => GT_ID,
+            OperatorType::LT => LT_ID,
+            OperatorType::EQ => EQ_ID,
+        }
+    }
+}
+
+/// How an operator packet specifies the extent of its children.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LengthType {
+    /// Length type ID 0: the total number of bits taken by the children, in 15 bits.
+    TotalBits,
+    /// Length type ID 1: the number of children, in 11 bits.
+    SubPacketCount,
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum PacketContent {
+    LITERAL(i64),
+    OPERATOR(OperatorType, Vec<Packet>),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Packet {
+    version: usize,
+    content: PacketContent,
+}
+
+impl Packet {
+    /// Encodes the packet as a hex transmission, which 'parse' decodes back into the same packet.
+    ///
+    /// Operators count their children (length type ID 1), since that header is 4 bits shorter, unless they have too
+    /// many of them to fit in 11 bits. Fails on negative literals, and on operators whose children fit in neither field.
+    pub fn encode(&self) -> Result<String, BitsError> {
+        self.encode_with(&mut |packet| match &packet.content {
+            PacketContent::OPERATOR(_, children)
+                if children.len() >= 1 << N_PACKETS_LENGTH_BITS =>
+            {
+                LengthType::TotalBits
+            }
+            _ => LengthType::SubPacketCount,
+        })
+    }
+
+    /// Like 'encode', but lets the caller pick the length type of every operator packet.
+    pub fn encode_with(
+        &self,
+        length_type: &mut impl FnMut(&Packet) -> LengthType,
+    ) -> Result<String, BitsError> {
+        let mut bits = Vec::new();
+        self.encode_bits(&mut bits, length_type)?;
+        Ok(bits_to_hex(bits))
+    }
+
+    fn encode_bits(
+        &self,
+        bits: &mut Vec<u8>,
+        length_type: &mut impl FnMut(&Packet) -> LengthType,
+    ) -> Result<(), BitsError> {
+        let offset = bits.len();
+        push_bits(bits, self.version as u64, VERSION_BITS);
+        match &self.content {
+            PacketContent::LITERAL(value) => {
+                if *value < 0 {
+                    return Err(BitsError::NegativeLiteral {
+                        offset,
+                        value: *value,
+                    });
+                }
+                push_bits(bits, LITERAL_ID as u64, TYPE_ID_BITS);
+                let value = *value as u64;
+                let n_groups =
+                    ((64 - value.leading_zeros() as usize).max(1)).div_ceil(LITERAL_GROUP_BITS);
+                for group_idx in (0..n_groups).rev() {
+                    bits.push(if group_idx > 0 { 1 } else { 0 });
+                    let group = (value >> (group_idx * LITERAL_GROUP_BITS)) & 0b1111;
+                    push_bits(bits, group, LITERAL_GROUP_BITS);
+                }
+            }
+            PacketContent::OPERATOR(op_type, children) => {
+                push_bits(bits, op_type.type_id() as u64, TYPE_ID_BITS);
+                match length_type(self) {
+                    LengthType::TotalBits => {
+                        // The children are encoded in place, and their length is only filled in once known.
+                        bits.push(0);
+                        let length_start = bits.len();
+                        push_bits(bits, 0, TOTAL_BITS_LENGTH_BITS);
+                        for child in children {
+                            child.encode_bits(bits, length_type)?;
+                        }
+                        let n_child_bits = bits.len() - length_start - TOTAL_BITS_LENGTH_BITS;
+                        if n_child_bits >= 1 << TOTAL_BITS_LENGTH_BITS {
+                            return Err(BitsError::LengthOverflow {
+                                offset,
+                                length_type: LengthType::TotalBits,
+                                length: n_child_bits,
+                            });
+                        }
+                        let mut length_bits = Vec::with_capacity(TOTAL_BITS_LENGTH_BITS);
+                        push_bits(
+                            &mut length_bits,
+                            n_child_bits as u64,
+                            TOTAL_BITS_LENGTH_BITS,
+                        );
+                        bits[length_start..length_start + TOTAL_BITS_LENGTH_BITS]
+                            .copy_from_slice(&length_bits);
+                    }
+                    LengthType::SubPacketCount => {
+                        if children.len() >= 1 << N_PACKETS_LENGTH_BITS {
+                            return Err(BitsError::LengthOverflow {
+                                offset,
+                                length_type: LengthType::SubPacketCount,
+                                length: children.len(),
+                            });
+                        }
+                        bits.push(1);
+                        push_bits(bits, children.len() as u64, N_PACKETS_LENGTH_BITS);
+                        for child in children {
+                            child.encode_bits(bits, length_type)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Pads the bits with zeros up to a whole number of hex digits, and converts them to hex.
+fn bits_to_hex(mut bits: Vec<u8>) -> String {
+    bits.resize(bits.len().div_ceil(4) * 4, 0);
+    bits.chunks(4)
+        .map(|nibble| {
+            let value = bin_to_dec(nibble) as u32;
+            std::char::from_digit(value, 16)
+                .unwrap()
+                .to_ascii_uppercase()
+        })
+        .collect()
+}
+
+/// Appends the 'n_bits' lowest bits of 'value', most significant first.
+fn push_bits(bits: &mut Vec<u8>, value: u64, n_bits: usize) {
+    assert!(
+        n_bits == 64 || value < 1 << n_bits,
+        "{} does not fit in {} bits",
+        value,
+        n_bits
+    );
+    bits.extend(
+        (0..n_bits)
+            .rev()
+            .map(|bit_idx| ((value >> bit_idx) & 1) as u8),
+    );
+}
+
+/// Everything that can go wrong while decoding a transmission, or encoding a packet into one. Offsets are in bits, from
+/// the start of the transmission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BitsError {
+    /// The transmission ended in the middle of a packet's 'field'.
+    Truncated {
+        offset: usize,
+        field: &'static str,
+    },
+    BadHexDigit {
+        offset: usize,
+        digit: char,
+    },
+    /// An operator without children, or a comparison (GT, LT, EQ) without exactly two of them.
+    WrongChildCount {
+        offset: usize,
+        type_id: usize,
+        n_children: usize,
+    },
+    /// A literal which does not fit in an i64.
+    LiteralOverflow {
+        offset: usize,
+    },
+    /// The bits after the outermost packet should all be zero.
+    TrailingPadding {
+        offset: usize,
+    },
+    /// Literals are encoded without a sign, so negative ones cannot be.
+    NegativeLiteral {
+        offset: usize,
+        value: i64,
+    },
+    /// An operator whose children are too long ('TotalBits') or too many ('SubPacketCount') for its length field.
+    LengthOverflow {
+        offset: usize,
+        length_type: LengthType,
+        length: usize,
+    },
+}
+
+impl fmt::Display for BitsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BitsError::Truncated { offset, field } => {
+                write!(f, "transmission ends in the {} at bit {}", field, offset)
+            }
+            BitsError::BadHexDigit { offset, digit } => {
+                write!(f, "invalid hex digit {:?} at bit {}", digit, offset)
+            }
+            BitsError::WrongChildCount {
+                offset,
+                type_id,
+                n_children,
+            } => write!(
+                f,
+                "operator with type ID {} at bit {} has {} children",
+                type_id, offset, n_children
+            ),
+            BitsError::LiteralOverflow { offset } => {
+                write!(f, "literal at bit {} does not fit in 64 bits", offset)
+            }
+            BitsError::TrailingPadding { offset } => {
+                write!(f, "non-zero padding at bit {}", offset)
+            }
+            BitsError::NegativeLiteral { offset, value } => {
+                write!(
+                    f,
+                    "cannot encode the negative literal {} at bit {}",
+                    value, offset
+                )
+            }
+            BitsError::LengthOverflow {
+                offset,
+                length_type,
+                length,
+            } => {
+                let field_bits = match length_type {
+                    LengthType::TotalBits => TOTAL_BITS_LENGTH_BITS,
+                    LengthType::SubPacketCount => N_PACKETS_LENGTH_BITS,
+                };
+                write!(
+                    f,
+                    "operator at bit {} has a length of {}, which does not fit in {} bits",
+                    offset, length, field_bits
+                )
+            }
+        }
+    }
+}
+
+/// Converts hex digits into their corresponding bits, most significant first.
+fn hex_str_to_bits(hex: &str) -> Result<Vec<u8>, BitsError> {
+    // Once I'm better with Rust I should use a BitSet.
+    let mut bits = Vec::with_capacity(hex.len() * 4);
+    for (idx, digit) in hex.chars().enumerate() {
+        let value = digit.to_digit(16).ok_or(BitsError::BadHexDigit {
+            offset: idx * 4,
+            digit,
+        })?;
+        push_bits(&mut bits, value as u64, 4);
+    }
+    Ok(bits)
+}
+
+/// Shifting instead of accumulating powers of two keeps 63-bit literals from overflowing.
+fn bin_to_dec(stuff: &[u8]) -> i64 {
+    stuff.iter().fold(0, |acc, &bit| (acc << 1) | bit as i64)
+}
+
+/// Returns the 'len' bits of a packet's 'field' starting at 'start', or an error if the packet ends first. 'offset' is
+/// the position of 'bits' in the whole transmission.
+fn take_bits<'a>(
+    bits: &'a [u8],
+    start: usize,
+    len: usize,
+    offset: usize,
+    field: &'static str,
+) -> Result<&'a [u8], BitsError> {
+    bits.get(start..start + len).ok_or(BitsError::Truncated {
+        offset: offset + start,
+        field,
+    })
+}
+
+/// Parses nested packets until all of 'bits' is used up.
+fn parse_sub_packets_n_total_bits(bits: &[u8], offset: usize) -> Result<Vec<Packet>, BitsError> {
+    let mut cur_bit = 0usize;
+    let mut packets = Vec::new();
+
+    while cur_bit < bits.len() {
+        let (packet, bits_consumed) = parse_packet_bits(&bits[cur_bit..], offset + cur_bit)?;
+        cur_bit += bits_consumed;
+        packets.push(packet);
+    }
+
+    Ok(packets)
+}
+
+/// Parses the next 'n_packets', assumed to be consecutively encoded in 'bits', returning the index after the last one.
+fn parse_sub_packets_n_packets(
+    bits: &[u8],
+    offset: usize,
+    n_packets: usize,
+) -> Result<(Vec<Packet>, usize), BitsError> {
+    let mut cur_bit = 0usize;
+    let mut packets = Vec::new();
+    for _ in 0..n_packets {
+        let (packet, n_consumed) = parse_packet_bits(&bits[cur_bit..], offset + cur_bit)?;
+        cur_bit += n_consumed;
+        packets.push(packet);
+    }
+
+    Ok((packets, cur_bit))
+}
+
+/// Parses the packet at the start of 'bits', which starts at bit 'offset' of the transmission, and returns it together
+/// with the number of bits it takes up.
+fn parse_packet_bits(bits: &[u8], offset: usize) -> Result<(Packet, usize), BitsError> {
+    let version = bin_to_dec(take_bits(bits, 0, VERSION_BITS, offset, "version")?) as usize;
+    let type_id = bin_to_dec(take_bits(
+        bits,
+        VERSION_BITS,
+        TYPE_ID_BITS,
+        offset,
+        "type ID",
+    )?) as usize;
+    let header_end = VERSION_BITS + TYPE_ID_BITS;
+
+    if type_id == LITERAL_ID {
+        let mut cur = header_end;
+        let mut lit_val = 0i64;
+        loop {
+            let group = take_bits(bits, cur, 1 + LITERAL_GROUP_BITS, offset, "literal")?;
+            if lit_val > i64::MAX >> LITERAL_GROUP_BITS {
+                return Err(BitsError::LiteralOverflow { offset });
+            }
+            lit_val = (lit_val << LITERAL_GROUP_BITS) | bin_to_dec(&group[1..]);
+            cur += 1 + LITERAL_GROUP_BITS;
+
+            if group[0] == 0 {
+                break;
+            }
+        }
+
+        Ok((
+            Packet {
+                version,
+                content: PacketContent::LITERAL(lit_val),
+            },
+            cur,
+        ))
+    } else {
+        // parse nested packets as needed
+        let bit_type_id = take_bits(bits, header_end, 1, offset, "length type ID")?[0];
+        let length_start = header_end + 1;
+        let (children, end) = if bit_type_id == 0 {
+            let packet_start = length_start + TOTAL_BITS_LENGTH_BITS;
+            let n_bits_sp = bin_to_dec(take_bits(
+                bits,
+                length_start,
+                TOTAL_BITS_LENGTH_BITS,
+                offset,
+                "sub-packet length",
+            )?) as usize;
+
+            let sub_bits = take_bits(bits, packet_start, n_bits_sp, offset, "sub-packets")?;
+            let children = parse_sub_packets_n_total_bits(sub_bits, offset + packet_start)?;
+            (children, packet_start + n_bits_sp)
+        } else {
+            let packet_start = length_start + N_PACKETS_LENGTH_BITS;
+            let n_packets = bin_to_dec(take_bits(
+                bits,
+                length_start,
+                N_PACKETS_LENGTH_BITS,
+                offset,
+                "sub-packet count",
+            )?) as usize;
+
+            let (children, bits_consumed) = parse_sub_packets_n_packets(
+                &bits[packet_start..],
+                offset + packet_start,
+                n_packets,
+            )?;
+            (children, packet_start + bits_consumed)
+        };
+
+        // TODO(andrei): Can we actually model the enum to support this mapping directly?
+        let op_type = match type_id {
+            SUM_ID => OperatorType::SUM,
+            PROD_ID => OperatorType::PROD,
+            MIN_ID => OperatorType::MIN,
+            MAX_ID => OperatorType::MAX,
+            GT_ID => OperatorType::GT,
+            LT_ID => OperatorType::LT,
+            EQ_ID => OperatorType::EQ,
+            _ => unreachable!("Type IDs only have {} bits", TYPE_ID_BITS),
+        };
+        let valid_count = match op_type {
+            OperatorType::GT | OperatorType::LT | OperatorType::EQ => children.len() == 2,
+            _ => !children.is_empty(),
+        };
+        if !valid_count {
+            return Err(BitsError::WrongChildCount {
+                offset,
+                type_id,
+                n_children: children.len(),
+            });
+        }
+
+        Ok((
+            Packet {
+                version,
+                content: PacketContent::OPERATOR(op_type, children),
+            },
+            end,
+        ))
+    }
+}
+
+/// Decodes the outermost packet of a hex transmission, returning it together with the number of bits it takes up.
+fn parse_packet(data: &str) -> Result<(Packet, usize), BitsError> {
+    let packet_bits = hex_str_to_bits(data.trim())?;
+    let (packet, end_idx) = parse_packet_bits(&packet_bits, 0)?;
+
+    // Transmissions are padded with zeros up to a whole number of hex digits (or more), but never with anything else.
+    if let Some(nonzero_idx) = packet_bits[end_idx..].iter().position(|&bit| bit != 0) {
+        return Err(BitsError::TrailingPadding {
+            offset: end_idx + nonzero_idx,
+        });
+    }
+
+    Ok((packet, end_idx))
+}
+
+fn version_sum(packet: &Packet) -> usize {
+    let child_version_sum = match &packet.content {
+        PacketContent::OPERATOR(_, sub) => sub.iter().map(version_sum).sum(),
+        PacketContent::LITERAL(_) => 0usize,
+    };
+    child_version_sum + packet.version
+}
+
+/// Evaluates the integer value of a packet - including all operations and children.
+fn evaluate(packet: &Packet) -> i64 {
+    match &packet.content {
+        PacketContent::OPERATOR(op_id, sub) => match op_id {
+            OperatorType::SUM => sub.iter().map(evaluate).sum::<i64>(),
+            OperatorType::PROD => sub.iter().map(evaluate).product::<i64>(),
+            OperatorType::MIN => sub.iter().map(evaluate).min().unwrap(),
+            OperatorType::MAX => sub.iter().map(evaluate).max().unwrap(),
+            OperatorType::GT => {
+                if evaluate(&sub[0]) > evaluate(&sub[1]) {
+                    1
+                } else {
+                    0
+                }
+            }
+            OperatorType::LT => {
+                if evaluate(&sub[0]) < evaluate(&sub[1]) {
+                    1
+                } else {
+                    0
+                }
+            }
+            OperatorType::EQ => {
+                if evaluate(&sub[0]) == evaluate(&sub[1]) {
+                    1
+                } else {
+                    0
+                }
+            }
+        },
+        PacketContent::LITERAL(value) => *value,
+    }
+}
+
+/// Decodes the outermost packet of the transmission.
+pub fn parse(input: &str) -> Packet {
+    parse_packet(input)
+        .unwrap_or_else(|err| panic!("Invalid transmission: {}", err))
+        .0
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(packet: &Packet) -> (Answer, Answer) {
+    let part_1_answer = version_sum(packet);
+    let part_2_answer = evaluate(packet);
+
+    (part_1_answer.into(), part_2_answer.into())
+}
+
+// TODO(andrei): Move entity definitions and related tests to their own file.
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use super::*;
+
+    /// A random packet tree with at most 'depth' levels of operators. Comparisons always get two children, like in
+    /// real transmissions, so the packets can also be evaluated.
+    fn random_packet(rng: &mut StdRng, depth: usize) -> Packet {
+        let version = rng.gen_range(0..8);
+        if depth == 0 || rng.gen_bool(0.3) {
+            let value = match rng.gen_range(0..3) {
+                0 => rng.gen_range(0..16),
+                1 => rng.gen_range(0..1_000_000),
+                _ => rng.gen_range(0..=i64::MAX),
+            };
+            return Packet {
+                version,
+                content: PacketContent::LITERAL(value),
+            };
+        }
+
+        let op_type = [
+            OperatorType::SUM,
+            OperatorType::PROD,
+            OperatorType::MIN,
+            OperatorType::MAX,
+            OperatorType::GT,
+            OperatorType::LT,
+            OperatorType::EQ,
+        ][rng.gen_range(0..7)];
+        let n_children = match op_type {
+            OperatorType::GT | OperatorType::LT | OperatorType::EQ => 2,
+            _ => rng.gen_range(1..5),
+        };
+        let children = (0..n_children)
+            .map(|_| random_packet(rng, depth - 1))
+            .collect();
+        Packet {
+            version,
+            content: PacketContent::OPERATOR(op_type, children),
+        }
+    }
+
+    #[test]
+    fn test_encode_examples() {
+        // The examples pad their transmissions with a few extra zeros, which we do not bother with.
+        let literal = parse("D2FE28");
+        assert_eq!(literal.encode().unwrap(), "D2FE28");
+
+        let total_bits = parse("38006F45291200");
+        let encoded = total_bits
+            .encode_with(&mut |_| LengthType::TotalBits)
+            .unwrap();
+        assert!("38006F45291200".starts_with(&encoded), "{}", encoded);
+
+        let n_packets = parse("EE00D40C823060");
+        assert!("EE00D40C823060".starts_with(&n_packets.encode().unwrap()));
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let mut rng = StdRng::seed_from_u64(16);
+        for _ in 0..500 {
+            let packet = random_packet(&mut rng, 4);
+            assert_eq!(parse_packet(&packet.encode().unwrap()).unwrap().0, packet);
+
+            // Mixing both length types in the same tree must not matter either.
+            let mut choice_rng = StdRng::seed_from_u64(rng.gen());
+            let mixed = packet
+                .encode_with(&mut |_| {
+                    if choice_rng.gen_bool(0.5) {
+                        LengthType::TotalBits
+                    } else {
+                        LengthType::SubPacketCount
+                    }
+                })
+                .unwrap();
+            let (decoded, n_bits) = parse_packet(&mixed).unwrap();
+            assert_eq!(decoded, packet);
+            assert!(
+                mixed.len() * 4 - n_bits < 4,
+                "Too much padding in {}",
+                mixed
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_many_children() {
+        // Too many children to count in 11 bits, so 'encode' falls back to the total length, which still fits in 15.
+        let children = (0..2500)
+            .map(|value| Packet {
+                version: 1,
+                content: PacketContent::LITERAL(value % 2),
+            })
+            .collect();
+        let packet = Packet {
+            version: 2,
+            content: PacketContent::OPERATOR(OperatorType::SUM, children),
+        };
+        let encoded = packet.encode().unwrap();
+        assert_eq!(hex_str_to_bits(&encoded).unwrap()[6], 0);
+        assert_eq!(parse(&encoded), packet);
+        assert_eq!(evaluate(&parse(&encoded)), 1250);
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let literal = |value| Packet {
+            version: 0,
+            content: PacketContent::LITERAL(value),
+        };
+        // The SUM header takes 18 bits, and the literal 1 takes 11.
+        let negative = Packet {
+            version: 1,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![literal(1), literal(-1)]),
+        };
+        assert_eq!(
+            negative.encode(),
+            Err(BitsError::NegativeLiteral {
+                offset: 29,
+                value: -1
+            })
+        );
+
+        // 2500 literals of 16 bits each are too many to count, and too long to measure.
+        let huge = Packet {
+            version: 2,
+            content: PacketContent::OPERATOR(OperatorType::MAX, vec![literal(255); 2500]),
+        };
+        let err = huge.encode().unwrap_err();
+        assert_eq!(
+            err,
+            BitsError::LengthOverflow {
+                offset: 0,
+                length_type: LengthType::TotalBits,
+                length: 40000
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "operator at bit 0 has a length of 40000, which does not fit in 15 bits"
+        );
+        assert_eq!(
+            huge.encode_with(&mut |_| LengthType::SubPacketCount),
+            Err(BitsError::LengthOverflow {
+                offset: 0,
+                length_type: LengthType::SubPacketCount,
+                length: 2500
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_packet("D").unwrap_err(),
+            BitsError::Truncated {
+                offset: 3,
+                field: "type ID"
+            }
+        );
+        assert_eq!(
+            parse_packet("D2FE").unwrap_err(),
+            BitsError::Truncated {
+                offset: 16,
+                field: "literal"
+            }
+        );
+        // The sub-packets claim to take 27 bits, but the transmission ends before that.
+        assert_eq!(
+            parse_packet("38006F45").unwrap_err(),
+            BitsError::Truncated {
+                offset: 22,
+                field: "sub-packets"
+            }
+        );
+        assert_eq!(
+            parse_packet("D2GE28").unwrap_err(),
+            BitsError::BadHexDigit {
+                offset: 8,
+                digit: 'G'
+            }
+        );
+        assert_eq!(
+            parse_packet("d2fe28").unwrap(),
+            parse_packet("D2FE28").unwrap()
+        );
+        assert_eq!(
+            parse_packet("D2FE29").unwrap_err(),
+            BitsError::TrailingPadding { offset: 23 }
+        );
+    }
+
+    #[test]
+    fn test_parse_wrong_child_count() {
+        let literal = |value| Packet {
+            version: 0,
+            content: PacketContent::LITERAL(value),
+        };
+        // The encoder does not check the operators, so it can build invalid ones.
+        let three_way = Packet {
+            version: 1,
+            content: PacketContent::OPERATOR(
+                OperatorType::GT,
+                vec![literal(1), literal(2), literal(3)],
+            ),
+        };
+        let wrapped = Packet {
+            version: 2,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![literal(4), three_way]),
+        };
+        // The SUM header takes 18 bits, and the literal 4 takes 11.
+        let err = parse_packet(&wrapped.encode().unwrap()).unwrap_err();
+        assert_eq!(
+            err,
+            BitsError::WrongChildCount {
+                offset: 29,
+                type_id: GT_ID,
+                n_children: 3
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "operator with type ID 5 at bit 29 has 3 children"
+        );
+
+        let empty = Packet {
+            version: 3,
+            content: PacketContent::OPERATOR(OperatorType::MIN, vec![]),
+        };
+        assert!(matches!(
+            parse_packet(&empty.encode().unwrap()),
+            Err(BitsError::WrongChildCount { n_children: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_literal_overflow() {
+        let max = Packet {
+            version: 0,
+            content: PacketContent::LITERAL(i64::MAX),
+        };
+        assert_eq!(parse_packet(&max.encode().unwrap()).unwrap().0, max);
+
+        // 17 groups of 4 bits are too many for an i64.
+        let mut bits = Vec::new();
+        push_bits(&mut bits, 0, VERSION_BITS);
+        push_bits(&mut bits, LITERAL_ID as u64, TYPE_ID_BITS);
+        for group_idx in (0..17).rev() {
+            bits.push(if group_idx > 0 { 1 } else { 0 });
+            push_bits(&mut bits, 0b1111, LITERAL_GROUP_BITS);
+        }
+        assert_eq!(
+            parse_packet(&bits_to_hex(bits)).unwrap_err(),
+            BitsError::LiteralOverflow { offset: 0 }
+        );
+    }
+
+    #[test]
+    fn test_basic_literal() {
+        let raw_packet = "D2FE28".to_string();
+        let (packet, _bits_consumed) = parse_packet(&raw_packet).unwrap();
+        let expected_packet = Packet {
+            version: 6usize,
+            content: PacketContent::LITERAL(2021i64),
+        };
+        assert_eq!(packet, expected_packet);
+    }
+
+    #[test]
+    fn test_eval_basic_literal() {
+        let raw_packet = "D2FE28".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let expected_eval = 2021i64;
+        let actual_eval = evaluate(&packet);
+        assert_eq!(actual_eval, expected_eval);
+    }
+
+    #[test]
+    fn test_basic_operator() {
+        let raw_packet = "38006F45291200".to_string();
+        let (packet, _bits_consumed) = parse_packet(&raw_packet).unwrap();
+        let sub_a = Packet {
+            version: 6usize,
+            content: PacketContent::LITERAL(10i64),
+        };
+        let sub_b = Packet {
+            version: 2usize,
+            content: PacketContent::LITERAL(20i64),
+        };
+        let expected_packet = Packet {
+            version: 1usize,
+            content: PacketContent::OPERATOR(OperatorType::LT, vec![sub_a, sub_b]),
+        };
+        assert_eq!(packet, expected_packet);
+    }
+
+    #[test]
+    fn test_eval_basic_operator() {
+        let raw_packet = "38006F45291200".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let expected_eval = 1i64;
+        let actual_eval = evaluate(&packet);
+        assert_eq!(actual_eval, expected_eval);
+    }
+
+    #[test]
+    fn test_operator_three_children() {
+        let raw_packet = "EE00D40C823060".to_string();
+        let (packet, _bits_consumed) = parse_packet(&raw_packet).unwrap();
+        let sub_a = Packet {
+            version: 2usize,
+            content: PacketContent::LITERAL(1i64),
+        };
+        let sub_b = Packet {
+            version: 4usize,
+            content: PacketContent::LITERAL(2i64),
+        };
+        let sub_c = Packet {
+            version: 1usize,
+            content: PacketContent::LITERAL(3i64),
+        };
+        let expected_packet = Packet {
+            version: 7usize,
+            content: PacketContent::OPERATOR(OperatorType::MAX, vec![sub_a, sub_b, sub_c]),
+        };
+        assert_eq!(packet, expected_packet);
+    }
+
+    #[test]
+    fn test_eval_operator_three_children() {
+        let raw_packet = "EE00D40C823060".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let expected_eval = 3i64;
+        let actual_eval = evaluate(&packet);
+        assert_eq!(actual_eval, expected_eval);
+    }
+
+    #[test]
+    fn test_nested_operators() {
+        let raw_packet = "8A004A801A8002F478".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let sub_a = Packet {
+            version: 6usize,
+            content: PacketContent::LITERAL(15i64),
+        };
+        let sub_b = Packet {
+            version: 5usize,
+            content: PacketContent::OPERATOR(OperatorType::MIN, vec![sub_a]),
+        };
+        let sub_c = Packet {
+            version: 1usize,
+            content: PacketContent::OPERATOR(OperatorType::MIN, vec![sub_b]),
+        };
+        let expected_packet = Packet {
+            version: 4usize,
+            content: PacketContent::OPERATOR(OperatorType::MIN, vec![sub_c]),
+        };
+        assert_eq!(packet, expected_packet);
+    }
+
+    #[test]
+    fn test_eval_nested_operators() {
+        let raw_packet = "8A004A801A8002F478".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let expected_eval = 15i64;
+        let actual_eval = evaluate(&packet);
+        assert_eq!(actual_eval, expected_eval);
+    }
+
+    #[test]
+    fn test_op_tree() {
+        let raw_packet = "620080001611562C8802118E34".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let sub_a_1 = Packet {
+            version: 0usize,
+            content: PacketContent::LITERAL(10i64),
+        };
+        let sub_a_2 = Packet {
+            version: 5usize,
+            content: PacketContent::LITERAL(11i64),
+        };
+        let sub_b_1 = Packet {
+            version: 0usize,
+            content: PacketContent::LITERAL(12i64),
+        };
+        let sub_b_2 = Packet {
+            version: 3usize,
+            content: PacketContent::LITERAL(13i64),
+        };
+        let sub_c = Packet {
+            version: 0usize,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![sub_a_1, sub_a_2]),
+        };
+        let sub_d = Packet {
+            version: 1usize,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![sub_b_1, sub_b_2]),
+        };
+        let expected_packet = Packet {
+            version: 3usize,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![sub_c, sub_d]),
+        };
+        assert_eq!(packet, expected_packet);
+    }
+
+    #[test]
+
+    fn test_op_tree_v2() {
+        let raw_packet = "C0015000016115A2E0802F182340".to_string();
+        let (packet, _) = parse_packet(&raw_packet).unwrap();
+        let sub_a_1 = Packet {
+            version: 0usize,
+            content: PacketContent::LITERAL(10i64),
+        };
+        let sub_a_2 = Packet {
+            version: 6usize,
+            content: PacketContent::LITERAL(11i64),
+        };
+        let sub_b_1 = Packet {
+            version: 7usize,
+            content: PacketContent::LITERAL(12i64),
+        };
+        let sub_b_2 = Packet {
+            version: 0usize,
+            content: PacketContent::LITERAL(13i64),
+        };
+        let sub_c = Packet {
+            version: 0usize,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![sub_a_1, sub_a_2]),
+        };
+        let sub_d = Packet {
+            version: 4usize,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![sub_b_1, sub_b_2]),
+        };
+        let expected_packet = Packet {
+            version: 6usize,
+            content: PacketContent::OPERATOR(OperatorType::SUM, vec![sub_c, sub_d]),
+        };
+        assert_eq!(packet, expected_packet);
+    }
+}
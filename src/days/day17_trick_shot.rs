@@ -0,0 +1,385 @@
+//! 2021 AoC Day 17: Trick Shot
+//!
+//! Spam shots from your cannon to see which ones land in a target area.
+//!
+//! Rather than simulating every shot, note that both coordinates follow triangular-number kinematics: after t steps, a
+//! coordinate launched with velocity v is at v * t - t * (t - 1) / 2 (until the drag stops x). Solving the quadratic
+//! for the target's edges gives the exact steps at which each axis is inside the target, so a velocity hits iff its x
+//! and y windows overlap.
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::answer::Answer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct World {
+    target_x: (i64, i64),
+    target_y: (i64, i64),
+}
+
+#[cfg(test)]
+impl World {
+    fn in_target(&self, x: i64, y: i64) -> bool {
+        x >= self.target_x.0 && x <= self.target_x.1 && y >= self.target_y.0 && y <= self.target_y.1
+    }
+
+    fn beyond_target(&self, x: i64, y: i64) -> bool {
+        x > self.target_x.1 || y < self.target_y.0
+    }
+}
+
+/// Launches from (0, 0) with (vx, vy) initial velocity, returning a (success, max_y) tuple.
+///
+/// Steps the probe until it passes the target, which assumes the target is ahead of and below the launcher.
+#[cfg(test)]
+fn shoot(vx: i64, vy: i64, world: &World, max_iter: usize) -> (bool, i64) {
+    let mut cur_x: i64 = 0;
+    let mut cur_y: i64 = 0;
+    let mut cur_vx: i64 = vx;
+    let mut cur_vy: i64 = vy;
+    let mut max_y: i64 = i64::MIN;
+
+    for _ in 0..max_iter {
+        cur_x += cur_vx;
+        cur_y += cur_vy;
+        if cur_vx > 0 {
+            cur_vx -= 1;
+        } else if cur_vx < 0 {
+            cur_vx += 1;
+        }
+        cur_vy -= 1;
+
+        if cur_y > max_y {
+            max_y = cur_y;
+        }
+
+        if world.in_target(cur_x, cur_y) {
+            return (true, max_y);
+        } else if world.beyond_target(cur_x, cur_y) {
+            return (false, max_y);
+        }
+    }
+
+    panic!(
+        "Could not reach our pass world after {} iterations...",
+        max_iter
+    );
+}
+
+/// An inclusive range of steps, open-ended if 'last' is 'None'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct StepWindow {
+    first: i64,
+    last: Option<i64>,
+}
+
+impl StepWindow {
+    fn overlaps(&self, other: &StepWindow) -> bool {
+        let first = self.first.max(other.first);
+        match (self.last, other.last) {
+            (Some(a), Some(b)) => first <= a.min(b),
+            (Some(last), None) | (None, Some(last)) => first <= last,
+            (None, None) => true,
+        }
+    }
+}
+
+/// Returns the integer t range where t^2 - b * t + c <= 0, if any.
+///
+/// A coordinate launched with velocity v is at least 'bound' after t steps iff t^2 - (2v + 1) * t + 2 * bound <= 0, as
+/// long as nothing stops it.
+fn quadratic_range(b: i64, c: i64) -> Option<(i64, i64)> {
+    let discriminant = b * b - 4 * c;
+    if discriminant < 0 {
+        return None;
+    }
+    let f = |t: i64| t * t - b * t + c;
+    // The integer square root only gets us within one of the exact roots.
+    let root = discriminant.isqrt();
+    let mut lo = (b - root).div_euclid(2);
+    let mut hi = (b + root).div_euclid(2) + 1;
+    while f(lo) > 0 {
+        lo += 1;
+    }
+    while f(lo - 1) <= 0 {
+        lo -= 1;
+    }
+    while hi >= lo && f(hi) > 0 {
+        hi -= 1;
+    }
+    while f(hi + 1) <= 0 {
+        hi += 1;
+    }
+    if lo > hi {
+        None
+    } else {
+        Some((lo, hi))
+    }
+}
+
+/// The first step at which x reaches at least 'bound', for vx >= 0, or 'None' if the drag stops it short.
+fn first_x_step_at_least(vx: i64, bound: i64) -> Option<i64> {
+    if bound <= vx {
+        return Some(1);
+    }
+    if vx * (vx + 1) / 2 < bound {
+        return None;
+    }
+    // x grows until step vx, so the first root is the one we want.
+    quadratic_range(2 * vx + 1, 2 * bound).map(|(first, _)| first)
+}
+
+/// The steps at which a shot with horizontal velocity 'vx' is within the target's x range.
+fn x_window(vx: i64, target_x: (i64, i64)) -> Option<StepWindow> {
+    // Shooting backwards is the mirror image of shooting forwards.
+    let (vx, (x_min, x_max)) = if vx < 0 {
+        (-vx, (-target_x.1, -target_x.0))
+    } else {
+        (vx, target_x)
+    };
+    let first = first_x_step_at_least(vx, x_min)?;
+    let last = first_x_step_at_least(vx, x_max + 1).map(|past| past - 1);
+    match last {
+        Some(last) if last < first => None,
+        _ => Some(StepWindow { first, last }),
+    }
+}
+
+/// The steps at which a shot with vertical velocity 'vy' is within the target's y range. y goes up and then back down,
+/// so there can be two separate windows.
+fn y_windows(vy: i64, target_y: (i64, i64)) -> Vec<StepWindow> {
+    let b = 2 * vy + 1;
+    let (above_min_first, above_min_last) = match quadratic_range(b, 2 * target_y.0) {
+        Some((first, last)) if last >= 1 => (first.max(1), last),
+        _ => return vec![],
+    };
+    let window = |first: i64, last: i64| {
+        (first <= last).then_some(StepWindow {
+            first,
+            last: Some(last),
+        })
+    };
+    match quadratic_range(b, 2 * (target_y.1 + 1)) {
+        // Too high between these steps.
+        Some((too_high_first, too_high_last)) => [
+            window(above_min_first, above_min_last.min(too_high_first - 1)),
+            window(above_min_first.max(too_high_last + 1), above_min_last),
+        ]
+        .into_iter()
+        .flatten()
+        .collect(),
+        None => window(above_min_first, above_min_last)
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Returns every initial velocity which lands the probe in the target area at some step.
+pub fn valid_velocities(world: &World) -> Result<Vec<(i64, i64)>, String> {
+    let (x_min, x_max) = world.target_x;
+    let (y_min, y_max) = world.target_y;
+
+    // Anything faster overshoots on the very first step.
+    let x_windows: Vec<(i64, StepWindow)> = (x_min.min(0)..=x_max.max(0))
+        .filter_map(|vx| x_window(vx, world.target_x).map(|window| (vx, window)))
+        .collect();
+
+    // Every shot going up passes through y = 0 again on its way down, one step for every step of its climb.
+    if y_min <= 0 && y_max >= 0 && x_windows.iter().any(|(_, window)| window.last.is_none()) {
+        return Err(format!(
+            "Infinitely many shots stop above the target area {:?} and fall into it.",
+            world
+        ));
+    }
+    let latest_x_step = x_windows
+        .iter()
+        .filter_map(|(_, window)| window.last)
+        .max()
+        .unwrap_or(0);
+    // Going down, a shot launched with vy > 0 hits y = 0 with speed vy + 1, so it misses targets below the launcher if
+    // vy is any larger than this. Targets above the launcher are missed if the first step overshoots them, and targets
+    // around it can only be hit at y = 0, at step 2 * vy + 1, which must still be in some x window.
+    let vy_max = y_min.abs().max(y_max.abs()).max(latest_x_step);
+
+    let mut velocities = Vec::new();
+    for vy in y_min.min(0)..=vy_max {
+        let y_windows = y_windows(vy, world.target_y);
+        for (vx, x_window) in &x_windows {
+            if y_windows.iter().any(|y_window| y_window.overlaps(x_window)) {
+                velocities.push((*vx, vy));
+            }
+        }
+    }
+
+    // Every point of the target area can be hit on the very first step, so this only happens for an empty area.
+    if velocities.is_empty() {
+        Err(format!("No shot can hit the target area {:?}.", world))
+    } else {
+        Ok(velocities)
+    }
+}
+
+/// The highest point of a shot's trajectory, counting the launcher itself.
+fn highest_point(vy: i64) -> i64 {
+    if vy > 0 {
+        vy * (vy + 1) / 2
+    } else {
+        0
+    }
+}
+
+/// Parses the target area from the puzzle text, e.g., "target area: x=20..30, y=-10..-5".
+pub fn try_parse(input: &str) -> Result<World, String> {
+    lazy_static! {
+        static ref TARGET_RE: Regex =
+            Regex::new(r"target area:\s*x=(-?\d+)\.\.(-?\d+),\s*y=(-?\d+)\.\.(-?\d+)").unwrap();
+    }
+
+    let caps = TARGET_RE
+        .captures(input)
+        .ok_or(format!("Invalid target area: {}", input.trim()))?;
+    let mut coords = [0i64; 4];
+    for (idx, coord) in coords.iter_mut().enumerate() {
+        *coord = caps[idx + 1]
+            .parse()
+            .map_err(|err| format!("Invalid coordinate {}: {}", &caps[idx + 1], err))?;
+    }
+    let [x_1, x_2, y_1, y_2] = coords;
+    Ok(World {
+        target_x: (x_1.min(x_2), x_1.max(x_2)),
+        target_y: (y_1.min(y_2), y_1.max(y_2)),
+    })
+}
+
+pub fn parse(input: &str) -> World {
+    try_parse(input).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Fails if the input is malformed, or if infinitely many shots hit the target area, or none at all.
+pub fn try_solve(input: &str) -> Result<(Answer, Answer), String> {
+    try_solve_parsed(&try_parse(input)?)
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    try_solve(input).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_solve_parsed(world: &World) -> Result<(Answer, Answer), String> {
+    let velocities = valid_velocities(world)?;
+    let max_y = velocities
+        .iter()
+        .map(|&(_, vy)| highest_point(vy))
+        .max()
+        .unwrap();
+
+    // The brute-force version's Part 2 answer (3186) was right on the first try, but took a few s to compute in
+    // '--release' mode.
+    Ok((max_y.into(), velocities.len().into()))
+}
+
+/// Like 'try_solve_parsed', but panics on targets which have no answer. Neither case happens for a puzzle input, whose
+/// target is always ahead of and below the launcher.
+pub fn solve_parsed(world: &World) -> (Answer, Answer) {
+    try_solve_parsed(world).unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// Follows the shot for a fixed number of steps. Unlike 'shoot', this does not assume the target is ahead of and
+    /// below the launcher, so it cannot give up early.
+    fn hits(vx: i64, vy: i64, world: &World) -> bool {
+        let (mut x, mut y, mut vx, mut vy) = (0, 0, vx, vy);
+        (0..200).any(|_| {
+            x += vx;
+            y += vy;
+            vx -= vx.signum();
+            vy -= 1;
+            world.in_target(x, y)
+        })
+    }
+
+    /// Tries every velocity in a range wide enough for the small targets below.
+    fn brute_force(world: &World) -> HashSet<(i64, i64)> {
+        let mut good_inits = HashSet::new();
+        for vx in -50..=50 {
+            for vy in -50..60 {
+                if hits(vx, vy, world) {
+                    good_inits.insert((vx, vy));
+                }
+            }
+        }
+        good_inits
+    }
+
+    fn world(target_x: (i64, i64), target_y: (i64, i64)) -> World {
+        World { target_x, target_y }
+    }
+
+    #[test]
+    fn test_quadratic_range() {
+        // (t - 2) * (t - 5) <= 0
+        assert_eq!(quadratic_range(7, 10), Some((2, 5)));
+        // t^2 - 5t + 5 has irrational roots, ~1.38 and ~3.62.
+        assert_eq!(quadratic_range(5, 5), Some((2, 3)));
+        assert_eq!(quadratic_range(1, 1), None);
+        // Both roots between the same two integers.
+        assert_eq!(quadratic_range(3, 2), Some((1, 2)));
+        assert_eq!(quadratic_range(0, -9), Some((-3, 3)));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let targets = [
+            world((20, 30), (-10, -5)),
+            // Above the launcher.
+            world((5, 20), (3, 12)),
+            // Behind the launcher.
+            world((-30, -20), (-10, -5)),
+            world((-30, -20), (4, 9)),
+            // Straight below.
+            world((-3, 2), (-8, -2)),
+        ];
+        for target in &targets {
+            let velocities = valid_velocities(target).unwrap();
+            let expected = brute_force(target);
+            assert_eq!(velocities.len(), expected.len(), "{:?}", target);
+            assert_eq!(velocities.into_iter().collect::<HashSet<_>>(), expected);
+
+            // 'shoot' agrees when the target is ahead of and below the launcher.
+            if target.target_x.0 > 0 && target.target_y.1 < 0 {
+                assert!(expected
+                    .iter()
+                    .all(|&(vx, vy)| shoot(vx, vy, target, 100000).0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_demo() {
+        let target = parse("target area: x=20..30, y=-10..-5");
+        assert_eq!(solve_parsed(&target), (45.into(), 112.into()));
+    }
+
+    #[test]
+    fn test_errors() {
+        // Any point can be hit on the very first step, so only an empty target area cannot be hit at all.
+        let err = valid_velocities(&world((5, 4), (-5, -1))).unwrap_err();
+        assert!(err.starts_with("No shot can hit"), "{}", err);
+
+        // Stalling at x = 10 (vx = 4) above a target which spans the launcher's height works for any vy >= 0.
+        let err = valid_velocities(&world((10, 10), (-5, 5))).unwrap_err();
+        assert!(err.starts_with("Infinitely many"), "{}", err);
+        // Stalling before the target is fine, though.
+        assert!(valid_velocities(&world((11, 12), (-5, 5))).is_ok());
+
+        // The solver reports the same errors, as well as malformed inputs.
+        let err = try_solve("target area: x=10..10, y=-5..5").unwrap_err();
+        assert!(err.starts_with("Infinitely many"), "{}", err);
+        assert!(try_solve("target area: x=1..2").is_err());
+        assert!(try_solve("target area: x=1..2, y=-99999999999999999999..3").is_err());
+    }
+}
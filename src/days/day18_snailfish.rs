@@ -0,0 +1,448 @@
+//! 2021 AoC Day 18: Snailfish
+//!
+//! Very tricky for me as a Rust beginner, since we need to build, manage, and operate upon (mutate) a tree, which is
+//! difficult to do with Rust's reference and borrowing semantics.
+//!
+//! After solving it in Python and continuing to struggle with the Rust implementation, I glanced at some other people's
+//! Rust solutions - some just operate on token sequences (simpler, IMO), others build trees using enum and Box. My
+//! initial attempt at Rc/RefCell trees did not go well, and they leaked, since children and parents pointed at each
+//! other.
+//!
+//! The final version keeps all the nodes of a number in an arena (a plain Vec) and links them by index. Parent links
+//! are just indices, so nothing leaks, the borrow checker has nothing to complain about, and cloning a number is a
+//! single Vec copy.
+use std::fmt;
+
+use crate::answer::Answer;
+
+/// Index of a node in its number's arena.
+type NodeId = usize;
+
+const EXPLODE_DEPTH: usize = 4;
+const SPLIT_THRESHOLD: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Value(u32),
+    Pair(NodeId, NodeId),
+}
+
+#[derive(Debug, Clone)]
+struct SnailNode {
+    kind: NodeKind,
+    parent: Option<NodeId>,
+}
+
+/// A snailfish number, stored as a tree in an arena.
+///
+/// Exploding a pair leaves its two children behind in the arena as garbage, but they are unreachable from 'root', and
+/// addition only copies over the reachable nodes, so the garbage never accumulates.
+#[derive(Debug, Clone)]
+pub struct SnailNumber {
+    nodes: Vec<SnailNode>,
+    root: NodeId,
+}
+
+impl SnailNumber {
+    fn push(&mut self, kind: NodeKind, parent: Option<NodeId>) -> NodeId {
+        self.nodes.push(SnailNode { kind, parent });
+        self.nodes.len() - 1
+    }
+
+    /// Copies the subtree at 'node' from 'other' into this arena, returning the index of the copy.
+    fn copy_from(&mut self, other: &SnailNumber, node: NodeId, parent: Option<NodeId>) -> NodeId {
+        match other.nodes[node].kind {
+            NodeKind::Value(val) => self.push(NodeKind::Value(val), parent),
+            NodeKind::Pair(left, right) => {
+                // Reserve our own slot first, since the children need to know where their parent lives.
+                let id = self.push(NodeKind::Value(0), parent);
+                let left_copy = self.copy_from(other, left, Some(id));
+                let right_copy = self.copy_from(other, right, Some(id));
+                self.nodes[id].kind = NodeKind::Pair(left_copy, right_copy);
+                id
+            }
+        }
+    }
+
+    /// Returns the regular numbers (leaves), left to right, together with their depth.
+    #[cfg(test)]
+    fn leaves(&self) -> Vec<(NodeId, usize)> {
+        let mut leaves = Vec::new();
+        // Push right before left, so the left child is popped (and visited) first.
+        let mut stack = vec![(self.root, 0usize)];
+        while let Some((node, depth)) = stack.pop() {
+            match self.nodes[node].kind {
+                NodeKind::Value(_) => leaves.push((node, depth)),
+                NodeKind::Pair(left, right) => {
+                    stack.push((right, depth + 1));
+                    stack.push((left, depth + 1));
+                }
+            }
+        }
+        leaves
+    }
+
+    fn value(&self, node: NodeId) -> u32 {
+        match self.nodes[node].kind {
+            NodeKind::Value(val) => val,
+            NodeKind::Pair(..) => panic!("Node {} is a pair, not a regular number.", node),
+        }
+    }
+
+    fn add_to_value(&mut self, node: NodeId, amount: u32) {
+        let new_val = self.value(node) + amount;
+        self.nodes[node].kind = NodeKind::Value(new_val);
+    }
+
+    /// Returns the leftmost pair nested inside four pairs in the subtree at 'node', which is 'depth' levels deep.
+    fn find_exploding(&self, node: NodeId, depth: usize) -> Option<NodeId> {
+        match self.nodes[node].kind {
+            NodeKind::Value(_) => None,
+            NodeKind::Pair(..) if depth >= EXPLODE_DEPTH => Some(node),
+            NodeKind::Pair(left, right) => self
+                .find_exploding(left, depth + 1)
+                .or_else(|| self.find_exploding(right, depth + 1)),
+        }
+    }
+
+    /// Returns the leftmost regular number which is 10 or greater in the subtree at 'node'.
+    fn find_splitting(&self, node: NodeId) -> Option<NodeId> {
+        match self.nodes[node].kind {
+            NodeKind::Value(val) if val >= SPLIT_THRESHOLD => Some(node),
+            NodeKind::Value(_) => None,
+            NodeKind::Pair(left, right) => self
+                .find_splitting(left)
+                .or_else(|| self.find_splitting(right)),
+        }
+    }
+
+    /// Returns the regular number right before the subtree at 'node' (or right after it, if not 'leftwards'), if any.
+    ///
+    /// Climbs the parent links until it can step over to the sibling on that side, and then descends along the edge of
+    /// the sibling which faces 'node'. This only visits O(depth) nodes, instead of listing all the leaves.
+    fn neighbor_leaf(&self, mut node: NodeId, leftwards: bool) -> Option<NodeId> {
+        loop {
+            let parent = self.nodes[node].parent?;
+            let (left, right) = match self.nodes[parent].kind {
+                NodeKind::Pair(left, right) => (left, right),
+                NodeKind::Value(_) => panic!("Parent {} is not a pair.", parent),
+            };
+            // Stepping left is only possible from a right child, and vice versa.
+            let (near, far) = if leftwards {
+                (right, left)
+            } else {
+                (left, right)
+            };
+            if node == near {
+                node = far;
+                break;
+            }
+            node = parent;
+        }
+        loop {
+            match self.nodes[node].kind {
+                NodeKind::Value(_) => return Some(node),
+                NodeKind::Pair(left, right) => node = if leftwards { right } else { left },
+            }
+        }
+    }
+
+    /// Explodes the leftmost pair nested inside four pairs, if any. Returns whether anything exploded.
+    fn explode(&mut self) -> bool {
+        let pair = match self.find_exploding(self.root, 0) {
+            Some(pair) => pair,
+            None => return false,
+        };
+        // In a reduced number nothing is nested deeper, and since we always explode before splitting, both children of
+        // the pair are regular numbers.
+        let (left, right) = match self.nodes[pair].kind {
+            NodeKind::Pair(left, right) => (left, right),
+            NodeKind::Value(_) => unreachable!(),
+        };
+        let (left_val, right_val) = (self.value(left), self.value(right));
+
+        if let Some(prev) = self.neighbor_leaf(pair, true) {
+            self.add_to_value(prev, left_val);
+        }
+        if let Some(next) = self.neighbor_leaf(pair, false) {
+            self.add_to_value(next, right_val);
+        }
+        self.nodes[pair].kind = NodeKind::Value(0);
+        true
+    }
+
+    /// Splits the leftmost regular number which is 10 or greater, if any. Returns whether anything was split.
+    fn split(&mut self) -> bool {
+        let node = match self.find_splitting(self.root) {
+            Some(node) => node,
+            None => return false,
+        };
+
+        let val = self.value(node);
+        let left = self.push(NodeKind::Value(val / 2), Some(node));
+        let right = self.push(NodeKind::Value(val.div_ceil(2)), Some(node));
+        self.nodes[node].kind = NodeKind::Pair(left, right);
+        true
+    }
+
+    /// Explodes and splits until neither applies anymore.
+    fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    /// Adds two numbers, returning the reduced result.
+    pub fn add(&self, other: &SnailNumber) -> SnailNumber {
+        let mut sum = SnailNumber {
+            nodes: Vec::with_capacity(self.nodes.len() + other.nodes.len() + 1),
+            root: 0,
+        };
+        let root = sum.push(NodeKind::Value(0), None);
+        let left = sum.copy_from(self, self.root, Some(root));
+        let right = sum.copy_from(other, other.root, Some(root));
+        sum.nodes[root].kind = NodeKind::Pair(left, right);
+        sum.reduce();
+        sum
+    }
+
+    fn magnitude_of(&self, node: NodeId) -> u64 {
+        match self.nodes[node].kind {
+            NodeKind::Value(val) => u64::from(val),
+            NodeKind::Pair(left, right) => {
+                3 * self.magnitude_of(left) + 2 * self.magnitude_of(right)
+            }
+        }
+    }
+
+    pub fn magnitude(&self) -> u64 {
+        self.magnitude_of(self.root)
+    }
+
+    fn fmt_node(&self, node: NodeId, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.nodes[node].kind {
+            NodeKind::Value(val) => write!(f, "{}", val),
+            NodeKind::Pair(left, right) => {
+                write!(f, "[")?;
+                self.fmt_node(left, f)?;
+                write!(f, ",")?;
+                self.fmt_node(right, f)?;
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl fmt::Display for SnailNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_node(self.root, f)
+    }
+}
+
+/// Used in very inefficient parsing
+fn find_matching_bracket(data: &[char]) -> usize {
+    let mut n_open: usize = 0;
+
+    for (idx, ch) in data.iter().enumerate() {
+        if ch == &'[' {
+            n_open += 1
+        } else if ch == &']' {
+            if n_open == 0 {
+                return idx;
+            }
+
+            n_open -= 1;
+        }
+    }
+
+    panic!("Invalid syntax");
+}
+
+fn parse_snail_pair_str(data: &str) -> SnailNumber {
+    let chars: Vec<char> = data.replace(' ', "").chars().collect();
+    let mut number = SnailNumber {
+        nodes: Vec::new(),
+        root: 0,
+    };
+    number.root = parse_snail_pair(&chars, &mut number, None);
+    number
+}
+
+/// Parses the pair spanning all of 'data' into the arena of 'number', returning its index.
+fn parse_snail_pair(data: &[char], number: &mut SnailNumber, p: Option<NodeId>) -> NodeId {
+    let cur = number.push(NodeKind::Value(0), p);
+    let (left, right_start) = if data[1] == '[' {
+        // it's a nested pair
+        let matching_idx = find_matching_bracket(&data[2..]) + 2;
+        (
+            parse_snail_pair(&data[1..(matching_idx + 1)], number, Some(cur)),
+            matching_idx + 1 + 1,
+        )
+    } else {
+        // it's just an integer
+        let literal = (data[1] as u32) - ('0' as u32);
+        // The '3' comes from '[N,' - i.e., point to whatever is after the comma.
+        (number.push(NodeKind::Value(literal), Some(cur)), 3)
+    };
+
+    let right = if data[right_start] == '[' {
+        let matching_idx = find_matching_bracket(&data[right_start + 1..]) + 1;
+        parse_snail_pair(
+            &data[right_start..(right_start + matching_idx + 1)],
+            number,
+            Some(cur),
+        )
+    } else {
+        let literal = (data[right_start] as u32) - ('0' as u32);
+        number.push(NodeKind::Value(literal), Some(cur))
+    };
+    number.nodes[cur].kind = NodeKind::Pair(left, right);
+    cur
+}
+
+pub fn parse(input: &str) -> Vec<SnailNumber> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_snail_pair_str)
+        .collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(numbers: &[SnailNumber]) -> (Answer, Answer) {
+    let total = numbers[1..]
+        .iter()
+        .fold(numbers[0].clone(), |acc, number| acc.add(number));
+
+    // Addition is not commutative, so we need to try both orders of every pair.
+    let mut largest_magnitude = 0u64;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                largest_magnitude = largest_magnitude.max(a.add(b).magnitude());
+            }
+        }
+    }
+
+    (total.magnitude().into(), largest_magnitude.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the kind of the node reached by following 'path' ('l' and 'r' steps) from the root.
+    fn kind_at(number: &SnailNumber, path: &str) -> NodeKind {
+        let mut node = number.root;
+        for step in path.chars() {
+            node = match (number.nodes[node].kind, step) {
+                (NodeKind::Pair(left, _), 'l') => left,
+                (NodeKind::Pair(_, right), 'r') => right,
+                _ => panic!("Invalid path {} in {}", path, number),
+            };
+        }
+        number.nodes[node].kind
+    }
+
+    #[test]
+    fn test_basic_parsing() {
+        let res = parse_snail_pair_str("[3,4]");
+        assert_eq!(kind_at(&res, "l"), NodeKind::Value(3));
+        assert_eq!(kind_at(&res, "r"), NodeKind::Value(4));
+    }
+
+    #[test]
+    fn test_basic_nested() {
+        let res = parse_snail_pair_str("[3, [5, 7]]");
+        assert_eq!(kind_at(&res, "rl"), NodeKind::Value(5));
+
+        let res_left = parse_snail_pair_str("[[5, 7], 9]");
+        assert_eq!(kind_at(&res_left, "lr"), NodeKind::Value(7));
+
+        let res_deep = parse_snail_pair_str("[[[1,2],5],[6,7]]");
+        assert_eq!(kind_at(&res_deep, "llr"), NodeKind::Value(2));
+    }
+
+    /// Tests snail number parsing by verifying that the 'to_string()' of the parsed number equals the original.
+    #[test]
+    fn test_advanced_parse() {
+        let samples: Vec<&str> = vec![
+            "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+            "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+            "[[[9,[3,8]],[[0,9],6]],[[[3,7],[4,9]],3]]",
+            "[[[[1,3],[5,3]],[[1,3],[8,7]]],[[[4,9],[6,9]],[[8,2],[7,3]]]]",
+        ];
+        for raw_str in samples {
+            let parsed = parse_snail_pair_str(raw_str);
+            assert_eq!(parsed.to_string(), raw_str);
+        }
+    }
+
+    #[test]
+    fn test_parent_links() {
+        let parsed = parse_snail_pair_str("[[1,2],3]");
+        assert_eq!(parsed.nodes[parsed.root].parent, None);
+        for (leaf, _) in parsed.leaves() {
+            let parent = parsed.nodes[leaf].parent.unwrap();
+            match parsed.nodes[parent].kind {
+                NodeKind::Pair(left, right) => assert!(left == leaf || right == leaf),
+                NodeKind::Value(_) => panic!("Parent of {} is not a pair.", leaf),
+            }
+        }
+    }
+
+    #[test]
+    fn test_explode() {
+        let samples: Vec<(&str, &str)> = vec![
+            ("[[[[[9,8],1],2],3],4]", "[[[[0,9],2],3],4]"),
+            ("[7,[6,[5,[4,[3,2]]]]]", "[7,[6,[5,[7,0]]]]"),
+            ("[[6,[5,[4,[3,2]]]],1]", "[[6,[5,[7,0]]],3]"),
+            (
+                "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+            ),
+            (
+                "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+                "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+            ),
+        ];
+        for (raw_input, raw_expected) in samples {
+            let mut number = parse_snail_pair_str(raw_input);
+            assert!(number.explode());
+            assert_eq!(number.to_string(), raw_expected);
+        }
+    }
+
+    #[test]
+    fn test_add_and_reduce() {
+        let a = parse_snail_pair_str("[[[[4,3],4],4],[7,[[8,4],9]]]");
+        let b = parse_snail_pair_str("[1,1]");
+        assert_eq!(a.add(&b).to_string(), "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]");
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let samples: Vec<(&str, u64)> = vec![
+            ("[[1,2],[[3,4],5]]", 143),
+            ("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", 1384),
+            ("[[[[1,1],[2,2]],[3,3]],[4,4]]", 445),
+            (
+                "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+                3488,
+            ),
+        ];
+        for (raw_input, expected) in samples {
+            assert_eq!(parse_snail_pair_str(raw_input).magnitude(), expected);
+        }
+    }
+
+    #[test]
+    fn test_sum_list() {
+        let numbers = parse("[1,1]\n[2,2]\n[3,3]\n[4,4]\n[5,5]\n[6,6]\n");
+        let total = numbers[1..]
+            .iter()
+            .fold(numbers[0].clone(), |acc, number| acc.add(number));
+        assert_eq!(total.to_string(), "[[[[5,0],[7,4]],[5,5]],[6,6]]");
+    }
+}
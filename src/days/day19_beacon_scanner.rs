@@ -1,29 +1,29 @@
-/// 2021 AoC Day 19: Beacon Scanner
-///
-/// While there are probably more efficient ways of solving this problem, I decided to solve it using a geometric
-/// computer vision approach for fun.
-///
-/// On the flip side, I learned several new things about Rust:
-///  - operator overloading
-///  - the basics of nalgebra
-extern crate nalgebra as na;
-
+//! 2021 AoC Day 19: Beacon Scanner
+//!
+//! While there are probably more efficient ways of solving this problem, I decided to solve it using a geometric
+//! computer vision approach for fun.
+//!
+//! On the flip side, I learned several new things about Rust:
+//!  - operator overloading
+//!  - the basics of nalgebra
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
 use std::ops;
 use std::str::FromStr;
-use std::time::Instant;
+
+use nalgebra as na;
 
 use na::geometry::{IsometryMatrix3, Rotation3, Translation3};
 use na::{Point3, Vector3};
 
+use crate::answer::Answer;
+
 type AdjacencyMatrix = Vec<Vec<i64>>;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-struct Point3d {
+pub struct Point3d {
     x: i64,
     y: i64,
     z: i64,
@@ -246,14 +246,14 @@ fn extract_keypoint_features(
             // }
 
             neighbors.push(Candidate {
-                cost: -1f64 * p.dist(q),
+                cost: -p.dist(q),
                 point: *q,
             });
         }
 
         while !neighbors.is_empty() {
             let n = neighbors.pop().unwrap();
-            let cost = -1f64 * n.cost;
+            let cost = -n.cost;
             if cost > max_dist {
                 break;
             }
@@ -383,9 +383,7 @@ fn match_triangles_naive(tri_a: &Triangle3d, tri_b: &Triangle3d) -> Option<Isome
     let rot = match_rotation_naive(tri_a, tri_b);
 
     // TODO(andrei): Clean up this ugly "functional" code.
-    let maybe_trans = rot
-        .map(|rotation| match_translation_naive(tri_a, tri_b, &rotation))
-        .flatten();
+    let maybe_trans = rot.and_then(|rotation| match_translation_naive(tri_a, tri_b, &rotation));
     maybe_trans.map(|trans| IsometryMatrix3::from_parts(trans, rot.unwrap()))
 }
 
@@ -564,16 +562,12 @@ fn compute_largest_manhattan(absolute_poses: &HashMap<i64, IsometryMatrix3<f64>>
     max_l1
 }
 
-fn day_19_beacon_scanner() {
-    // let input_fname = "input/19-demo.txt";
-    let input_fname = "input/19.txt";
-    let max_dist = 1500f64;
-    let max_neighbors = 3usize;
-    let scanner_beacons: Vec<Spec> = fs::read_to_string(input_fname)
-        .expect("Unable to read file.")
-        .split('\n')
+/// Returns the beacons seen by each scanner, in the scanner's own coordinate frame.
+pub fn parse(input: &str) -> HashMap<i64, Vec<Point3d>> {
+    let scanner_beacons: Vec<Spec> = input
+        .lines()
         .filter(|x| !x.is_empty())
-        .map(|x| str_to_coords_or_scanner(x))
+        .map(str_to_coords_or_scanner)
         .collect();
 
     let mut scanners: HashMap<i64, Vec<Point3d>> = HashMap::new();
@@ -583,14 +577,21 @@ fn day_19_beacon_scanner() {
             Spec::NewScanner(scanner_id) => {
                 cur_scanner = scanner_id;
             }
-            Spec::NewBeacon(point) => scanners
-                .entry(cur_scanner)
-                .or_insert_with(Vec::new)
-                .push(point),
+            Spec::NewBeacon(point) => scanners.entry(cur_scanner).or_default().push(point),
         }
     }
 
-    let start = Instant::now();
+    scanners
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(scanners: &HashMap<i64, Vec<Point3d>>) -> (Answer, Answer) {
+    let max_dist = 1500f64;
+    let max_neighbors = 3usize;
+
     let scanner_keypoint_features: HashMap<i64, Vec<(Triangle3d, f64)>> = scanners
         .iter()
         .map(|(k, v)| (*k, extract_keypoint_features(v, max_dist, max_neighbors)))
@@ -608,20 +609,10 @@ fn day_19_beacon_scanner() {
     //       several point clouds.
     //
     // In my case, 367 is correct for Part 1 - I just needed to process the pose graph properly.
-    let n_unique = count_unique_points(&scanners, &absolute_poses);
-    println!("{}", n_unique);
+    let n_unique = count_unique_points(scanners, &absolute_poses);
 
     // Part 2
     let largest_distance = compute_largest_manhattan(&absolute_poses);
-    println!("Largest manhattan distance: {}", largest_distance);
-
-    let solve_ms = start.elapsed().as_micros();
-    println!(
-        "Solver took {}µs. (Excluding input parsing and process initialization.)",
-        solve_ms
-    );
-}
 
-fn main() {
-    day_19_beacon_scanner();
+    (n_unique.into(), largest_distance.into())
 }
@@ -1,19 +1,20 @@
-/// 2021 AoC Day 20: Trench Map
-///
-/// For each position, count the pixels in the surrounding 3x3 region to get a binary number which you then use to do a
-/// look-up and find the correct value for that pixel after the current iteration. Needs to support infiniely large
-/// inputs and perform this update many times in order to produce the final result.
-///
-/// Hint used: If all zeroes cause a '#', then it's useful to look at what all ones goes into! Turns out, it has to be
-/// '0', since otherwise the answer would be infinity.
-use std::fs;
+//! 2021 AoC Day 20: Trench Map
+//!
+//! For each position, count the pixels in the surrounding 3x3 region to get a binary number which you then use to do a
+//! look-up and find the correct value for that pixel after the current iteration. Needs to support infiniely large
+//! inputs and perform this update many times in order to produce the final result.
+//!
+//! Hint used: If all zeroes cause a '#', then it's useful to look at what all ones goes into! Turns out, it has to be
+//! '0', since otherwise the answer would be infinity.
+use crate::answer::Answer;
+use crate::grid::Grid;
 
 const DARK_PIXEL: char = '.';
 const LIGHT_PIXEL: char = '#';
 const N_LOOKUP_BITS: usize = 512;
 
 struct World {
-    finite_map: Vec<Vec<bool>>,
+    finite_map: Grid<bool>,
     // The color representing EVERYTHING beyond the known world.
     background: bool,
 }
@@ -39,7 +40,8 @@ fn pixel_to_bool(pixel: char) -> bool {
     }
 }
 
-fn parse_input(raw_string: &String) -> (Vec<bool>, Vec<Vec<bool>>) {
+/// Returns the image enhancement lookup table and the input image.
+pub fn parse(raw_string: &str) -> (Vec<bool>, Grid<bool>) {
     let parts: Vec<&str> = raw_string.split("\n\n").collect();
     let lookup_bits: Vec<bool> = parts[0].chars().map(pixel_to_bool).collect();
 
@@ -47,16 +49,12 @@ fn parse_input(raw_string: &String) -> (Vec<bool>, Vec<Vec<bool>>) {
         panic!("Invalid number of lookup bits. Got: {}", lookup_bits.len());
     }
 
-    let mut initial_image = Vec::new();
-    for row in parts[1].split("\n") {
-        let row_vec: Vec<bool> = row.chars().map(pixel_to_bool).collect();
-        initial_image.push(row_vec);
-    }
+    let initial_image = Grid::parse_with(parts[1], pixel_to_bool);
 
     (lookup_bits, initial_image)
 }
 
-fn bin_to_dec(bits: &Vec<bool>) -> i64 {
+fn bin_to_dec(bits: &[bool]) -> i64 {
     let mut idx: i64 = (bits.len() - 1) as i64;
     let mut acc: i64 = 0;
     let mut exp: i64 = 1;
@@ -65,7 +63,7 @@ fn bin_to_dec(bits: &Vec<bool>) -> i64 {
         if bits[idx as usize] {
             acc += exp;
         }
-        exp = exp * 2;
+        exp *= 2;
         idx -= 1;
         if idx < 0 {
             break;
@@ -75,49 +73,40 @@ fn bin_to_dec(bits: &Vec<bool>) -> i64 {
     acc
 }
 
-fn conv2d_world(world: &World, lookup: &Vec<bool>, kernel_size: usize) -> World {
-    if kernel_size % 2 == 0 {
+fn conv2d_world(world: &World, lookup: &[bool], kernel_size: usize) -> World {
+    if kernel_size.is_multiple_of(2) {
         panic!("Kernel size must be odd");
     }
 
-    let off: i32 = (kernel_size / 2) as i32;
-    let total_padding = 1 * 2;
-    let n_in_rows = world.finite_map.len();
-    let n_in_cols = world.finite_map[0].len();
+    let off = (kernel_size / 2) as isize;
+    let total_padding = 2;
+    let in_map = &world.finite_map;
 
     // Prepare the output image
-    let mut out_image = Vec::new();
-    for _ in 0..(n_in_rows + total_padding) {
-        out_image.push(vec![false; n_in_cols + total_padding]);
-    }
-
-    for out_row in 0..out_image.len() {
-        for out_col in 0..out_image[0].len() {
-            let mut bits: Vec<bool> = Vec::new();
-
-            for row_off in -off..=off {
-                for col_off in -off..=off {
-                    let row_in = (out_row as i32) - 1i32 + row_off;
-                    let col_in = (out_col as i32) - 1i32 + col_off;
-
-                    let val = if row_in >= 0
-                        && row_in < (n_in_rows as i32)
-                        && col_in >= 0
-                        && col_in < (n_in_cols as i32)
-                    {
-                        world.finite_map[row_in as usize][col_in as usize]
-                    } else {
-                        world.background
-                    };
-
-                    bits.push(val);
-                }
+    let mut out_image = Grid::new(
+        in_map.rows() + total_padding,
+        in_map.cols() + total_padding,
+        false,
+    );
+
+    for out_pos in out_image.positions() {
+        let mut bits: Vec<bool> = Vec::new();
+
+        for row_off in -off..=off {
+            for col_off in -off..=off {
+                let row_in = (out_pos.0 as isize) - 1 + row_off;
+                let col_in = (out_pos.1 as isize) - 1 + col_off;
+                let val = in_map
+                    .get_signed(row_in, col_in)
+                    .copied()
+                    .unwrap_or(world.background);
+
+                bits.push(val);
             }
-
-            let bit_value: i64 = bin_to_dec(&bits);
-            let pixel = lookup[bit_value as usize];
-            out_image[out_row][out_col] = pixel;
         }
+
+        let bit_value: i64 = bin_to_dec(&bits);
+        out_image[out_pos] = lookup[bit_value as usize];
     }
 
     let new_background: bool = if world.background {
@@ -134,12 +123,13 @@ fn conv2d_world(world: &World, lookup: &Vec<bool>, kernel_size: usize) -> World
 
 /// The hacky v1 solution. Tries to avoid explicitly modeling the infinite background but doesn't produce the right
 /// output for Part 2, probably due to some artifacts regarding the border.
+#[allow(dead_code)]
 fn conv2d_with_lookup(
-    base_image: &Vec<Vec<bool>>,
-    lookup: &Vec<bool>,
+    base_image: &[Vec<bool>],
+    lookup: &[bool],
     kernel_size: usize,
 ) -> Vec<Vec<bool>> {
-    if kernel_size % 2 == 0 {
+    if kernel_size.is_multiple_of(2) {
         panic!("Kernel size must be odd");
     }
 
@@ -196,52 +186,34 @@ fn conv2d_with_lookup(
     out_image
 }
 
-fn count_lights(data: &Vec<Vec<bool>>) -> usize {
-    let mut n_lights: usize = 0;
-
-    let border = 0;
-
-    for row in &data[border..data.len() - border] {
-        for el in &row[border..data.len() - border] {
-            if *el {
-                n_lights += 1;
-            }
-        }
-    }
-
-    n_lights
+fn count_lights(data: &Grid<bool>) -> usize {
+    data.iter().filter(|el| **el).count()
 }
 
-fn print_image(data: &Vec<Vec<bool>>) {
-    for row in data {
-        let row_str: String = row.iter().map(bool_to_pixel).collect();
-        println!("{}", row_str);
-    }
+#[allow(dead_code)]
+fn print_image(data: &Grid<bool>) {
+    println!("{}", data.render(bool_to_pixel));
 }
 
-fn day_20_trench_map() {
-    let input_fname = "input/20.txt";
-    // let input_fname = "input/20-demo.txt";
-    let raw_lines: String = fs::read_to_string(input_fname).expect("Unable to read file.");
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
 
-    let (lookup_bits, initial_image) = parse_input(&raw_lines);
+pub fn solve_parsed((lookup_bits, initial_image): &(Vec<bool>, Grid<bool>)) -> (Answer, Answer) {
     let mut cur_world = World {
         finite_map: initial_image.clone(),
         background: false,
     };
+    let mut part_1_result = 0usize;
 
     for iter_idx in 0..50 {
-        if iter_idx % 5 == 0 {
-            println!("Iter idx: {}", iter_idx + 1);
-        }
-        cur_world = conv2d_world(&cur_world, &lookup_bits, 3);
+        cur_world = conv2d_world(&cur_world, lookup_bits, 3);
 
         if iter_idx == 1 {
             // 5392 is not correct... (too high)
             // 5223 is also too high
             // 4917 was right - the trick is to think about border condidtions
-            let part_1_result = count_lights(&cur_world.finite_map);
-            println!("Part 1 result: {}", part_1_result);
+            part_1_result = count_lights(&cur_world.finite_map);
         }
     }
 
@@ -251,9 +223,6 @@ fn day_20_trench_map() {
     //  18038 is still too high? Wtf.
     //  16389 is right - got it INSTANTLY after coding the problem in the non-hacky way! Woo!
     let part_2_result = count_lights(&cur_world.finite_map);
-    println!("Part 2 result: {}", part_2_result);
-}
 
-fn main() {
-    day_20_trench_map();
+    (part_1_result.into(), part_2_result.into())
 }
@@ -0,0 +1,784 @@
+//! 2021 AoC Day 21: Dirac Dice
+//!
+//! Rolling dice and forking universes - keep track of every possible roll of a set of dice at every round, using the
+//! now very familiar histogram pattern to avoid exponential blow-ups. Special care must be taken in order to properly
+//! account for when a game ends.
+//!
+//! Hint used: I looked at the Reddit thread and the high-level things were already things I knew, like the fact that a
+//! lot of game instances are actually duplicated many, many times. One hint, which is basically a less "fancy" way of
+//! solving the problem is to keep a list of active games and their count, even if it may have a few dupes, and process
+//! game tuples in the list basis, rather than being overly fancy about it with a fully tabulated state space. Let's see
+//! if that works...
+//!
+//! This hint was not useful - turns out, I was already one step ahead of this approach and I ended up getting the same
+//! (incorrect) result with the list-based implementation. After a bit of thread reading (still not looking at code!),
+//! looking at posts in which people were re-stating the problem idea, it hit me - the second player's "universe forks"
+//! NEVER HAPPEN if the first player already won!
+//!
+//! This was the final bug I had to fix in order to get the correct answer to this question!
+//!
+//! The histogram version hardcodes the rules of the puzzle. 'DiracGame' generalizes them (board size, die, number of
+//! players, etc.) and counts the wins with a memoised recursion over game states instead, which ends by itself since
+//! every turn increases a score. Weighting the same recursion by probabilities instead of universe counts gives the
+//! exact odds of winning with any die, which 'monte_carlo' estimates by playing many games with a random one.
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign, Mul};
+use std::rc::Rc;
+
+use lazy_static::lazy_static;
+use num_traits::{One, Zero};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use regex::Regex;
+
+use crate::answer::Answer;
+
+#[cfg(test)]
+type UniverseHistogram = Vec<Vec<Vec<Vec<usize>>>>;
+
+pub trait Die {
+    fn roll(&mut self) -> usize;
+}
+
+/// A die whose rolls are independent of each other, so they follow a fixed distribution.
+pub trait RandomOutcomes {
+    /// The possible values of every roll, with their relative weights.
+    fn outcomes(&self) -> Vec<(usize, u64)>;
+}
+
+struct DeterministicDie {
+    n_sides: usize,
+    _next_roll: usize,
+    _n_rolls: usize,
+}
+
+impl Die for DeterministicDie {
+    fn roll(&mut self) -> usize {
+        let roll = self._next_roll;
+        self._next_roll += 1;
+        if self._next_roll > self.n_sides {
+            self._next_roll = 1;
+        }
+        self._n_rolls += 1;
+        roll
+    }
+}
+
+impl DeterministicDie {
+    fn new(n_sides: usize) -> Self {
+        Self {
+            n_sides,
+            _next_roll: 1,
+            _n_rolls: 0,
+        }
+    }
+}
+
+/// A fair die, seeded so that simulations can be reproduced.
+pub struct RandomDie {
+    n_sides: usize,
+    rng: StdRng,
+}
+
+impl RandomDie {
+    pub fn new(n_sides: usize, seed: u64) -> Self {
+        Self {
+            n_sides,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Die for RandomDie {
+    fn roll(&mut self) -> usize {
+        self.rng.gen_range(1..=self.n_sides)
+    }
+}
+
+impl RandomOutcomes for RandomDie {
+    fn outcomes(&self) -> Vec<(usize, u64)> {
+        (1..=self.n_sides).map(|side| (side, 1)).collect()
+    }
+}
+
+/// A loaded die, which rolls side 'i + 1' with a probability proportional to 'weights[i]'.
+pub struct WeightedDie {
+    weights: Vec<u64>,
+    distribution: WeightedIndex<u64>,
+    rng: StdRng,
+}
+
+impl WeightedDie {
+    /// Fails if there are no weights, or they are all zero.
+    pub fn new(weights: &[u64], seed: u64) -> Result<Self, String> {
+        Ok(Self {
+            weights: weights.to_vec(),
+            distribution: WeightedIndex::new(weights)
+                .map_err(|err| format!("Invalid die weights {:?}: {}", weights, err))?,
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+}
+
+impl Die for WeightedDie {
+    fn roll(&mut self) -> usize {
+        self.distribution.sample(&mut self.rng) + 1
+    }
+}
+
+/// Splits the universe into one copy for each of its sides, which is what the exact solver counts. Rolling it follows
+/// a random one of those universes, in which it looks just like a fair die.
+pub struct DiracDie {
+    n_sides: usize,
+}
+
+impl DiracDie {
+    pub fn new(n_sides: usize) -> Self {
+        Self { n_sides }
+    }
+}
+
+impl Die for DiracDie {
+    fn roll(&mut self) -> usize {
+        rand::thread_rng().gen_range(1..=self.n_sides)
+    }
+}
+
+impl RandomOutcomes for DiracDie {
+    /// One universe per side.
+    fn outcomes(&self) -> Vec<(usize, u64)> {
+        (1..=self.n_sides).map(|side| (side, 1)).collect()
+    }
+}
+
+impl RandomOutcomes for WeightedDie {
+    fn outcomes(&self) -> Vec<(usize, u64)> {
+        (1..)
+            .zip(self.weights.iter().copied())
+            .filter(|&(_, weight)| weight > 0)
+            .collect()
+    }
+}
+
+struct Player {
+    die: Rc<RefCell<dyn Die>>,
+    score: usize,
+    state: usize,
+}
+
+impl Player {
+    fn new(start_state: usize, die: Rc<RefCell<dyn Die>>) -> Self {
+        Self {
+            die,
+            score: 0,
+            state: start_state,
+        }
+    }
+
+    fn turn(&mut self, game: &DiracGame) -> usize {
+        let jmp: usize = (0..game.rolls_per_turn)
+            .map(|_| self.die.borrow_mut().roll())
+            .sum();
+        self.state = (self.state + jmp) % game.board_size;
+
+        self.score += self.state + 1;
+
+        self.score
+    }
+}
+
+/// Plays until somebody wins, returning the index of the winner.
+fn play(players: &mut [Player], game: &DiracGame) -> usize {
+    loop {
+        for (idx, player) in players.iter_mut().enumerate() {
+            if player.turn(game) >= game.winning_score {
+                return idx;
+            }
+        }
+    }
+}
+
+/// Plays 'n_games' games with the given die, returning the fraction of them won by each player.
+pub fn monte_carlo(
+    game: &DiracGame,
+    starts: &[usize],
+    die: Rc<RefCell<dyn Die>>,
+    n_games: usize,
+) -> Vec<f64> {
+    assert_eq!(starts.len(), game.n_players, "Need one start per player.");
+    let mut wins = vec![0usize; game.n_players];
+    for _ in 0..n_games {
+        // The players count positions from zero.
+        let mut players: Vec<Player> = starts
+            .iter()
+            .map(|&start| Player::new(start - 1, die.clone()))
+            .collect();
+        wins[play(&mut players, game)] += 1;
+    }
+    wins.into_iter()
+        .map(|n_wins| n_wins as f64 / n_games as f64)
+        .collect()
+}
+
+fn part_1(start: (usize, usize)) -> usize {
+    let die = DeterministicDie::new(PART_1_GAME.die_sides);
+    // The players count positions from zero.
+    let (start_p1, start_p2) = (start.0 - 1, start.1 - 1);
+
+    let die_box = Rc::new(RefCell::new(die));
+
+    let mut players = [
+        Player::new(start_p1, die_box.clone()),
+        Player::new(start_p2, die_box.clone()),
+    ];
+    play(&mut players, &PART_1_GAME);
+    // 867 888 is too big..., but I had '==' instead of '>=' in the final if-statement...
+    let loser_score = players.iter().map(|player| player.score).min().unwrap();
+    let n_rolls = die_box.borrow()._n_rolls;
+    loser_score * n_rolls
+}
+
+/// The rules of a game played with a Dirac die, which splits the universe into one copy for every possible roll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiracGame {
+    /// Positions go from 1 to 'board_size', wrapping around.
+    pub board_size: usize,
+    pub die_sides: usize,
+    pub rolls_per_turn: usize,
+    pub winning_score: usize,
+    pub n_players: usize,
+}
+
+/// The practice game from Part 1, played with a deterministic 100-sided die.
+pub const PART_1_GAME: DiracGame = DiracGame {
+    board_size: 10,
+    die_sides: 100,
+    rolls_per_turn: 3,
+    winning_score: 1000,
+    n_players: 2,
+};
+
+/// The game from Part 2.
+pub const PART_2_GAME: DiracGame = DiracGame {
+    board_size: 10,
+    die_sides: 3,
+    rolls_per_turn: 3,
+    winning_score: 21,
+    n_players: 2,
+};
+
+/// The (position, score) of every player, starting with the one whose turn it is.
+type GameState = Vec<(usize, usize)>;
+
+/// What the game tree is weighted by: universe counts, or probabilities.
+pub trait Weight: Copy + Zero + One + Mul<Output = Self> + AddAssign {}
+
+impl<T: Copy + Zero + One + Mul<Output = T> + AddAssign> Weight for T {}
+
+/// A universe count which turns into 'None' once it overflows, instead of wrapping around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CheckedCount(Option<u128>);
+
+impl Add for CheckedCount {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        CheckedCount(self.0.zip(other.0).and_then(|(a, b)| a.checked_add(b)))
+    }
+}
+
+impl AddAssign for CheckedCount {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Mul for CheckedCount {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        CheckedCount(self.0.zip(other.0).and_then(|(a, b)| a.checked_mul(b)))
+    }
+}
+
+impl Zero for CheckedCount {
+    fn zero() -> Self {
+        CheckedCount(Some(0))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == Some(0)
+    }
+}
+
+impl One for CheckedCount {
+    fn one() -> Self {
+        CheckedCount(Some(1))
+    }
+}
+
+impl DiracGame {
+    /// Returns every total of a turn's rolls, given the weights of a single roll's outcomes.
+    fn turn_distribution<T: Weight>(&self, outcomes: &[(usize, T)]) -> Vec<(usize, T)> {
+        let max_side = outcomes.iter().map(|&(side, _)| side).max().unwrap_or(0);
+        let mut weights = vec![T::one()];
+        for _ in 0..self.rolls_per_turn {
+            let mut next = vec![T::zero(); weights.len() + max_side];
+            for (total, &weight) in weights.iter().enumerate() {
+                for &(side, side_weight) in outcomes {
+                    next[total + side] += weight * side_weight;
+                }
+            }
+            weights = next;
+        }
+        weights
+            .into_iter()
+            .enumerate()
+            .filter(|(_, weight)| !weight.is_zero())
+            .collect()
+    }
+
+    /// Returns how many universes produce each total of a turn's rolls, e.g., [(3, 1), (4, 3), ..., (9, 1)] for three
+    /// rolls of a 3-sided die.
+    fn roll_distribution(&self) -> Vec<(usize, u128)> {
+        let outcomes: Vec<(usize, u128)> = DiracDie::new(self.die_sides)
+            .outcomes()
+            .into_iter()
+            .map(|(side, n_universes)| (side, n_universes as u128))
+            .collect();
+        self.turn_distribution(&outcomes)
+    }
+
+    /// Returns the number of universes in which each player wins, given their (1-based) starting positions.
+    ///
+    /// Panics if there are too many universes to count in a u128, e.g., with a die of five or more sides.
+    pub fn count_wins(&self, starts: &[usize]) -> Vec<u128> {
+        self.try_count_wins(starts)
+            .expect("Too many universes to count.")
+    }
+
+    /// Like 'count_wins', but returns 'None' if there are too many universes to count in a u128.
+    pub fn try_count_wins(&self, starts: &[usize]) -> Option<Vec<u128>> {
+        let rolls: Vec<(usize, CheckedCount)> = self
+            .roll_distribution()
+            .into_iter()
+            .map(|(total, n_universes)| (total, CheckedCount(Some(n_universes))))
+            .collect();
+        self.weigh_wins(starts, &rolls)
+            .into_iter()
+            .map(|wins| wins.0)
+            .collect()
+    }
+
+    /// Returns the probability of each player winning when playing with 'die', which can be weighted.
+    ///
+    /// Unlike the universe counts, this accounts for short games being more likely than long ones, since every roll
+    /// splits the probability of a game between the outcomes. With a 'DiracDie', these are the odds of winning in a
+    /// random universe.
+    pub fn win_probabilities(&self, starts: &[usize], die: &dyn RandomOutcomes) -> Vec<f64> {
+        let outcomes = die.outcomes();
+        let total_weight: u64 = outcomes.iter().map(|&(_, weight)| weight).sum();
+        let probabilities: Vec<(usize, f64)> = outcomes
+            .into_iter()
+            .map(|(side, weight)| (side, weight as f64 / total_weight as f64))
+            .collect();
+        self.weigh_wins(starts, &self.turn_distribution(&probabilities))
+    }
+
+    /// Sums up the weights of the games won by each player, given the weights of every total of a turn's rolls.
+    fn weigh_wins<T: Weight>(&self, starts: &[usize], rolls: &[(usize, T)]) -> Vec<T> {
+        assert_eq!(starts.len(), self.n_players, "Need one start per player.");
+        assert!(
+            starts
+                .iter()
+                .all(|start| (1..=self.board_size).contains(start)),
+            "Invalid starting positions {:?}",
+            starts
+        );
+        let state: GameState = starts.iter().map(|&start| (start, 0)).collect();
+        self.weigh_wins_from(&state, rolls, &mut HashMap::new())
+    }
+
+    /// Weighs the wins of every player, in the same order as 'state'.
+    fn weigh_wins_from<T: Weight>(
+        &self,
+        state: &GameState,
+        rolls: &[(usize, T)],
+        cache: &mut HashMap<GameState, Vec<T>>,
+    ) -> Vec<T> {
+        if let Some(wins) = cache.get(state) {
+            return wins.clone();
+        }
+
+        let n_players = state.len();
+        let (position, score) = state[0];
+        let mut wins = vec![T::zero(); n_players];
+        for &(total, weight) in rolls {
+            let new_position = (position - 1 + total) % self.board_size + 1;
+            let new_score = score + new_position;
+            if new_score >= self.winning_score {
+                // The other players never get to roll in these universes.
+                wins[0] += weight;
+                continue;
+            }
+
+            // The next player goes first, and the one who just moved goes last.
+            let mut next_state: GameState = state[1..].to_vec();
+            next_state.push((new_position, new_score));
+            let next_wins = self.weigh_wins_from(&next_state, rolls, cache);
+            for (player, player_wins) in wins.iter_mut().enumerate() {
+                *player_wins += weight * next_wins[(player + n_players - 1) % n_players];
+            }
+        }
+
+        cache.insert(state.clone(), wins.clone());
+        wins
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::needless_range_loop)]
+fn update_state(
+    state: &UniverseHistogram,
+    out_state: &mut UniverseHistogram,
+    max_score: usize,
+) -> (usize, usize) {
+    let mut p1_wins = 0usize;
+    let mut p2_wins = 0usize;
+
+    for p1_pos in 1..=10 {
+        for p2_pos in 1..=10 {
+            for p1_score in 0..max_score {
+                for p2_score in 0..max_score {
+                    // TOOD(andrei): Can we use a fast memset-like op here?
+                    out_state[p1_pos][p2_pos][p1_score][p2_score] = 0usize;
+                }
+            }
+        }
+    }
+
+    for p1_pos in 1..=10 {
+        for p2_pos in 1..=10 {
+            for p1_score in 0..max_score {
+                for p2_score in 0..max_score {
+                    let count = state[p1_pos][p2_pos][p1_score][p2_score];
+                    if 0usize == count {
+                        continue;
+                    }
+
+                    for (p1_roll, p1_roll_count) in [1, 3, 6, 7, 6, 3, 1].iter().enumerate() {
+                        // This logic must be done in the outer roll loop (P1's rolls), as otherwise if we just do
+                        // `p1_wins += ...` and continue, we will go to the next P2 roll value (which is also
+                        // technically never reached), add P1's wins again, etc., thereby multi-counting P1's rolls once
+                        // for every value of the inner loop - hence the exact 7x over-estimation of P1's win count bug.
+                        let p1_roll_val = p1_roll + 3;
+                        let mut new_p1_pos = p1_pos + p1_roll_val;
+                        if new_p1_pos > 10 {
+                            new_p1_pos -= 10;
+                        }
+                        let new_p1_score = p1_score + new_p1_pos;
+                        if new_p1_score >= max_score {
+                            p1_wins += p1_roll_count * count;
+                            continue;
+                        }
+
+                        for (p2_roll, p2_roll_count) in [1, 3, 6, 7, 6, 3, 1].iter().enumerate() {
+                            let p2_roll_val: usize = p2_roll + 3;
+                            let mut new_p2_pos: usize = p2_pos + p2_roll_val;
+                            if new_p2_pos > 10 {
+                                new_p2_pos -= 10;
+                            }
+                            let new_p2_score = p2_score + new_p2_pos;
+                            if new_p2_score >= max_score {
+                                p2_wins += p1_roll_count * p2_roll_count * count;
+                            } else {
+                                // No winner so far
+                                out_state[new_p1_pos][new_p2_pos][new_p1_score][new_p2_score] +=
+                                    p1_roll_count * p2_roll_count * count;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (p1_wins, p2_wins)
+}
+
+/// Tabulates the universes by (positions, scores) for every round, with the puzzle's rules hardcoded. The tests check
+/// 'DiracGame' against it.
+#[cfg(test)]
+fn part_2_histogram(start: (usize, usize)) -> usize {
+    let mut state_a = vec![vec![vec![vec![0usize; 21usize]; 21usize]; 11usize]; 11usize];
+    let mut state_b = vec![vec![vec![vec![0usize; 21usize]; 21usize]; 11usize]; 11usize];
+    let max_score = 21usize;
+    // Every player scores at least one point per turn, so no game can last longer than this many rounds.
+    let n_stages = max_score;
+    state_a[start.0][start.1][0][0] = 1;
+    let mut total_p1_wins = 0usize;
+    let mut total_p2_wins = 0usize;
+
+    for round in 0..n_stages {
+        if round % 2 == 0 {
+            let (p1_wins, p2_wins) = update_state(&state_a, &mut state_b, max_score);
+            total_p1_wins += p1_wins;
+            total_p2_wins += p2_wins;
+        } else {
+            let (p1_wins, p2_wins) = update_state(&state_b, &mut state_a, max_score);
+            total_p1_wins += p1_wins;
+            total_p2_wins += p2_wins;
+        }
+    }
+
+    total_p1_wins.max(total_p2_wins)
+}
+
+/// Returns the (1-based) starting positions of the two players, given lines like "Player 1 starting position: 4".
+pub fn try_parse(input: &str) -> Result<(usize, usize), String> {
+    lazy_static! {
+        static ref START_RE: Regex =
+            Regex::new(r"Player (\d+) starting position:\s*(\d+)").unwrap();
+    }
+
+    let mut starts = [None; 2];
+    for caps in START_RE.captures_iter(input) {
+        let player: usize = caps[1]
+            .parse()
+            .map_err(|err| format!("Invalid player {}: {}", &caps[1], err))?;
+        let position: usize = caps[2]
+            .parse()
+            .map_err(|err| format!("Invalid starting position {}: {}", &caps[2], err))?;
+        if !(1..=10).contains(&position) {
+            return Err(format!(
+                "Invalid starting position {} for player {}",
+                position, player
+            ));
+        }
+        match player {
+            1 | 2 => starts[player - 1] = Some(position),
+            _ => {
+                return Err(format!(
+                    "Dirac Dice is a two-player game, but got player {}",
+                    player
+                ))
+            }
+        }
+    }
+
+    Ok((
+        starts[0].ok_or("Missing starting position for player 1")?,
+        starts[1].ok_or("Missing starting position for player 2")?,
+    ))
+}
+
+pub fn parse(input: &str) -> (usize, usize) {
+    try_parse(input).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+fn part_2(start: (usize, usize)) -> u128 {
+    let wins = PART_2_GAME.count_wins(&[start.0, start.1]);
+    wins.into_iter().max().unwrap()
+}
+
+pub fn solve_parsed(&start: &(usize, usize)) -> (Answer, Answer) {
+    (part_1(start).into(), part_2(start).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Plays out every single universe, which is only feasible for tiny games.
+    fn count_wins_naive(game: &DiracGame, state: &[(usize, usize)], player: usize) -> Vec<u128> {
+        let mut wins = vec![0u128; game.n_players];
+        let mut rolls = vec![0usize];
+        for _ in 0..game.rolls_per_turn {
+            rolls = rolls
+                .iter()
+                .flat_map(|total| (1..=game.die_sides).map(move |side| total + side))
+                .collect();
+        }
+
+        for total in rolls {
+            let mut state = state.to_vec();
+            let (position, score) = state[player];
+            let new_position = (position - 1 + total) % game.board_size + 1;
+            state[player] = (new_position, score + new_position);
+            if score + new_position >= game.winning_score {
+                wins[player] += 1;
+            } else {
+                let next_wins = count_wins_naive(game, &state, (player + 1) % game.n_players);
+                for (total_wins, next) in wins.iter_mut().zip(next_wins) {
+                    *total_wins += next;
+                }
+            }
+        }
+        wins
+    }
+
+    /// Checks that the simulated win rates are close to the exact probabilities.
+    fn assert_close(estimates: &[f64], exact: &[f64], tolerance: f64) {
+        assert_eq!(estimates.len(), exact.len());
+        for (estimate, exact) in estimates.iter().zip(exact) {
+            assert!(
+                (estimate - exact).abs() < tolerance,
+                "{:?} vs. {:?}",
+                estimates,
+                exact
+            );
+        }
+    }
+
+    #[test]
+    fn test_dice() {
+        let mut deterministic = DeterministicDie::new(3);
+        let rolls: Vec<usize> = (0..5).map(|_| deterministic.roll()).collect();
+        assert_eq!(rolls, vec![1, 2, 3, 1, 2]);
+
+        let mut random = RandomDie::new(6, 1);
+        let rolls: Vec<usize> = (0..100).map(|_| random.roll()).collect();
+        assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+        let mut same_seed = RandomDie::new(6, 1);
+        assert!(rolls.iter().all(|&roll| roll == same_seed.roll()));
+
+        assert_eq!(
+            random.outcomes(),
+            vec![(1, 1), (2, 1), (3, 1), (4, 1), (5, 1), (6, 1)]
+        );
+
+        let mut loaded = WeightedDie::new(&[0, 1, 0], 1).unwrap();
+        assert!((0..100).all(|_| loaded.roll() == 2));
+        assert_eq!(loaded.outcomes(), vec![(2, 1)]);
+        assert!(WeightedDie::new(&[0, 0], 1).is_err());
+        assert!(WeightedDie::new(&[], 1).is_err());
+
+        let mut dirac = DiracDie::new(3);
+        assert_eq!(dirac.outcomes(), vec![(1, 1), (2, 1), (3, 1)]);
+        assert!((0..100).all(|_| (1..=3).contains(&dirac.roll())));
+    }
+
+    #[test]
+    fn test_parse() {
+        let demo = "Player 1 starting position: 4\nPlayer 2 starting position: 8\n";
+        assert_eq!(try_parse(demo), Ok((4, 8)));
+        assert!(try_parse("Player 1 starting position: 4").is_err());
+        assert!(
+            try_parse("Player 1 starting position: 11\nPlayer 2 starting position: 8").is_err()
+        );
+        assert!(try_parse("Player 3 starting position: 1").is_err());
+        assert!(try_parse("Player 1 starting position: 99999999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_monte_carlo() {
+        let starts = [4, 8];
+        let die = RandomDie::new(3, 7);
+        let exact = PART_2_GAME.win_probabilities(&starts, &die);
+        assert!((exact.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        // Player 1 wins in more universes, and is also more likely to win.
+        let counts = PART_2_GAME.count_wins(&starts);
+        assert!(counts[0] > counts[1] && exact[0] > exact[1]);
+
+        let estimates = monte_carlo(&PART_2_GAME, &starts, Rc::new(RefCell::new(die)), 20_000);
+        assert_close(&estimates, &exact, 0.02);
+
+        // Following a random universe of the Dirac die is the same thing.
+        let dirac = DiracDie::new(3);
+        assert_eq!(PART_2_GAME.win_probabilities(&starts, &dirac), exact);
+        let estimates = monte_carlo(&PART_2_GAME, &starts, Rc::new(RefCell::new(dirac)), 20_000);
+        assert_close(&estimates, &exact, 0.03);
+    }
+
+    #[test]
+    fn test_monte_carlo_weighted() {
+        let starts = [3, 7];
+        let die = WeightedDie::new(&[1, 2, 3], 5).unwrap();
+        let exact = PART_2_GAME.win_probabilities(&starts, &die);
+        let estimates = monte_carlo(&PART_2_GAME, &starts, Rc::new(RefCell::new(die)), 20_000);
+        assert_close(&estimates, &exact, 0.02);
+
+        // A die which always rolls the same side makes for a very predictable game.
+        let die = WeightedDie::new(&[0, 0, 1], 5).unwrap();
+        let exact = PART_2_GAME.win_probabilities(&starts, &die);
+        let estimates = monte_carlo(&PART_2_GAME, &starts, Rc::new(RefCell::new(die)), 10);
+        assert_eq!(estimates, exact);
+        assert!(exact.contains(&1.0));
+    }
+
+    #[test]
+    fn test_roll_distribution() {
+        assert_eq!(
+            PART_2_GAME.roll_distribution(),
+            vec![(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)]
+        );
+    }
+
+    #[test]
+    fn test_demo_wins() {
+        assert_eq!(
+            PART_2_GAME.count_wins(&[4, 8]),
+            vec![444356092776315, 341960390180808]
+        );
+
+        // A bigger die splits the universe so often that the counts overflow.
+        let big_die = DiracGame {
+            die_sides: 5,
+            ..PART_2_GAME
+        };
+        assert_eq!(big_die.try_count_wins(&[4, 8]), None);
+    }
+
+    #[test]
+    fn test_matches_histogram() {
+        // The histogram solver takes a while in debug builds, so only check a few starts.
+        for start in [(4, 8), (7, 3), (1, 10), (10, 1), (5, 5)] {
+            assert_eq!(part_2(start), part_2_histogram(start) as u128);
+        }
+    }
+
+    #[test]
+    fn test_other_games() {
+        let games = [
+            DiracGame {
+                board_size: 5,
+                die_sides: 2,
+                rolls_per_turn: 2,
+                winning_score: 8,
+                n_players: 3,
+            },
+            DiracGame {
+                board_size: 7,
+                die_sides: 4,
+                rolls_per_turn: 1,
+                winning_score: 10,
+                n_players: 2,
+            },
+            DiracGame {
+                board_size: 3,
+                die_sides: 6,
+                rolls_per_turn: 2,
+                winning_score: 5,
+                n_players: 1,
+            },
+        ];
+        for game in &games {
+            let starts: Vec<usize> = (0..game.n_players)
+                .map(|player| player % game.board_size + 1)
+                .collect();
+            let state: Vec<(usize, usize)> = starts.iter().map(|&start| (start, 0)).collect();
+            assert_eq!(
+                game.count_wins(&starts),
+                count_wins_naive(game, &state, 0),
+                "{:?}",
+                game
+            );
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! 2021 AoC Day 22: Reactor Reboot
+//!
+//! Turn cuboids of reactor cubes on and off, then count how many cubes are on.
+//!
+//! Part 1 could be brute-forced on a 101^3 grid, but Part 2 spans hundreds of thousands of cubes in each direction.
+//! Instead, we keep a list of signed cuboids: every time a cuboid is added, the intersections with everything seen so
+//! far are added with the opposite sign, so overlapping regions are never counted twice (inclusion-exclusion). The
+//! total number of lit cubes is then just the sum of the signed volumes.
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::answer::Answer;
+
+/// Part 1 only considers cubes with all coordinates in [-50, 50].
+const INIT_REGION_RADIUS: i64 = 50;
+
+/// An axis-aligned box of cubes. Both ends of every range are inclusive, like in the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub min: [i64; 3],
+    pub max: [i64; 3],
+}
+
+impl Cuboid {
+    pub fn new(min: [i64; 3], max: [i64; 3]) -> Self {
+        assert!(
+            (0..3).all(|axis| min[axis] <= max[axis]),
+            "Invalid cuboid {:?} -> {:?}",
+            min,
+            max
+        );
+        Cuboid { min, max }
+    }
+
+    /// A cube centered at the origin, spanning [-radius, radius] along every axis.
+    pub fn centered(radius: i64) -> Self {
+        Cuboid::new([-radius; 3], [radius; 3])
+    }
+
+    /// Returns the number of cubes inside the cuboid.
+    pub fn volume(&self) -> i64 {
+        (0..3)
+            .map(|axis| self.max[axis] - self.min[axis] + 1)
+            .product()
+    }
+
+    /// Returns the overlap of the two cuboids, or 'None' if they do not touch.
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let mut min = [0i64; 3];
+        let mut max = [0i64; 3];
+        for axis in 0..3 {
+            min[axis] = self.min[axis].max(other.min[axis]);
+            max[axis] = self.max[axis].min(other.max[axis]);
+            if min[axis] > max[axis] {
+                return None;
+            }
+        }
+        Some(Cuboid { min, max })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebootStep {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+impl FromStr for RebootStep {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        lazy_static! {
+            static ref STEP_RE: Regex = Regex::new(
+                r"^(on|off) x=(-?\d+)\.\.(-?\d+),y=(-?\d+)\.\.(-?\d+),z=(-?\d+)\.\.(-?\d+)$"
+            )
+            .unwrap();
+        }
+
+        let caps = STEP_RE
+            .captures(spec.trim())
+            .ok_or(format!("Invalid reboot step: {}", spec))?;
+        let coord = |idx: usize| i64::from_str(&caps[idx]).map_err(|err| err.to_string());
+        let min = [coord(2)?, coord(4)?, coord(6)?];
+        let max = [coord(3)?, coord(5)?, coord(7)?];
+        if (0..3).any(|axis| min[axis] > max[axis]) {
+            return Err(format!("Reversed range in reboot step: {}", spec));
+        }
+        Ok(RebootStep {
+            on: &caps[1] == "on",
+            cuboid: Cuboid::new(min, max),
+        })
+    }
+}
+
+/// Runs the reboot steps, only considering the cubes inside 'region', if given, and returns the number of lit cubes.
+fn count_lit(steps: &[RebootStep], region: Option<&Cuboid>) -> i64 {
+    // Maps each cuboid to how many times its volume is counted. Merging identical cuboids keeps the map from blowing
+    // up, since the same intersections keep showing up over and over again.
+    let mut signed: HashMap<Cuboid, i64> = HashMap::new();
+
+    for step in steps {
+        let cuboid = match region {
+            Some(region) => match step.cuboid.intersection(region) {
+                Some(clipped) => clipped,
+                None => continue,
+            },
+            None => step.cuboid,
+        };
+
+        // Cancel out whatever was already counted inside the new cuboid, so it ends up being counted zero times...
+        let mut updates: HashMap<Cuboid, i64> = HashMap::new();
+        for (existing, &count) in &signed {
+            if let Some(overlap) = existing.intersection(&cuboid) {
+                *updates.entry(overlap).or_insert(0) -= count;
+            }
+        }
+        // ...then count it once if it is being turned on.
+        if step.on {
+            *updates.entry(cuboid).or_insert(0) += 1;
+        }
+
+        for (updated, delta) in updates {
+            let count = signed.entry(updated).or_insert(0);
+            *count += delta;
+            if *count == 0 {
+                signed.remove(&updated);
+            }
+        }
+    }
+
+    signed
+        .iter()
+        .map(|(cuboid, count)| cuboid.volume() * count)
+        .sum()
+}
+
+pub fn parse(input: &str) -> Vec<RebootStep> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.parse().unwrap())
+        .collect()
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(steps: &[RebootStep]) -> (Answer, Answer) {
+    let init_region = Cuboid::centered(INIT_REGION_RADIUS);
+    let part_1 = count_lit(steps, Some(&init_region));
+    let part_2 = count_lit(steps, None);
+    (part_1.into(), part_2.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersection() {
+        let a = Cuboid::new([10, 10, 10], [12, 12, 12]);
+        let b = Cuboid::new([11, 11, 11], [13, 13, 13]);
+        assert_eq!(a.volume(), 27);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new([11, 11, 11], [12, 12, 12]))
+        );
+        assert_eq!(a.intersection(&b), b.intersection(&a));
+
+        // Touching along a single face still overlaps, since the ranges are inclusive.
+        let c = Cuboid::new([12, 0, 0], [20, 20, 20]);
+        assert_eq!(a.intersection(&c).unwrap().volume(), 9);
+
+        let far = Cuboid::new([13, 10, 10], [14, 12, 12]);
+        assert_eq!(a.intersection(&far), None);
+    }
+
+    #[test]
+    fn test_parse_step() {
+        let step: RebootStep = "off x=-54112..-39298,y=-85059..-49293,z=-27449..7877"
+            .parse()
+            .unwrap();
+        assert!(!step.on);
+        assert_eq!(step.cuboid.min, [-54112, -85059, -27449]);
+        assert_eq!(step.cuboid.max, [-39298, -49293, 7877]);
+        assert!("on x=1..2,y=3..4".parse::<RebootStep>().is_err());
+        assert!("on x=5..1,y=3..4,z=0..0"
+            .parse::<RebootStep>()
+            .unwrap_err()
+            .starts_with("Reversed range"));
+    }
+
+    /// Checks the signed counting against a brute-force count on the small example.
+    #[test]
+    fn test_count_lit_small() {
+        let steps = parse(
+            "on x=10..12,y=10..12,z=10..12\n\
+             on x=11..13,y=11..13,z=11..13\n\
+             off x=9..11,y=9..11,z=9..11\n\
+             on x=10..10,y=10..10,z=10..10\n",
+        );
+        for n_steps in 1..=steps.len() {
+            let mut lit = std::collections::HashSet::new();
+            for step in &steps[..n_steps] {
+                for x in step.cuboid.min[0]..=step.cuboid.max[0] {
+                    for y in step.cuboid.min[1]..=step.cuboid.max[1] {
+                        for z in step.cuboid.min[2]..=step.cuboid.max[2] {
+                            if step.on {
+                                lit.insert((x, y, z));
+                            } else {
+                                lit.remove(&(x, y, z));
+                            }
+                        }
+                    }
+                }
+            }
+            assert_eq!(count_lit(&steps[..n_steps], None), lit.len() as i64);
+        }
+        assert_eq!(count_lit(&steps, None), 39);
+    }
+}
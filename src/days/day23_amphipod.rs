@@ -0,0 +1,767 @@
+//! 2021 AoC Day 23: Amphipod
+//!
+//! Find the most efficient way of moving amphipods around such that they each end up in their target room.
+//!
+//! After finishing the problem, I realize that I should probably keep track of the amphipod state as a char array,
+//! maybe a 2D one, rather than a list of entities. That would reduce all pathing checks to O(1) look-ups and simplify
+//! many parts of the code. We'd still need to track a list of amphipod entities so we can generate each one's possible
+//! moves, but we should be able to code this such that we still maintain the high performance of the char array
+//! look-ups.
+//!
+//! The search is A*: worlds are explored cheapest-first (counting a lower bound on the energy still needed), keyed by a
+//! packed encoding of the burrow, so the first solved world we pop is the optimal one, and most of the expensive worlds
+//! are never even generated.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::answer::Answer;
+
+/// Corridor row
+const COR_ROW: i32 = 0;
+/// UR = upper-room
+const UR_ROW: i32 = 1;
+
+/// The rows which get unfolded from the diagram for Part 2, right below the upper-room row.
+const PART_2_ROWS: &[&str] = &["  #D#C#B#A#", "  #D#B#A#C#"];
+
+// Index of the last column in the corridor.
+const END_COL: i32 = 10;
+
+const AMBER_COL: i32 = 2;
+const BRONZE_COL: i32 = 4;
+const COPPER_COL: i32 = 6;
+const DESERT_COL: i32 = 8;
+
+/// Bits used to encode a single burrow cell (empty, or one of the four kinds) in a packed world.
+const BITS_PER_CELL: usize = 3;
+
+const OK_STOP_COL: &[bool] = &[
+    // 0, 1, 3, 5, 7, 9, 10 are OK to stop in after getting out
+    true, true, false, true, false, true, false, true, false, true, true,
+];
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+enum Kind {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Kind {
+    fn move_cost(&self) -> i64 {
+        match *self {
+            Kind::Amber => 1,
+            Kind::Bronze => 10,
+            Kind::Copper => 100,
+            Kind::Desert => 1000,
+        }
+    }
+
+    fn initial(&self) -> &'static str {
+        match *self {
+            Kind::Amber => "A",
+            Kind::Bronze => "B",
+            Kind::Copper => "C",
+            Kind::Desert => "D",
+        }
+    }
+
+    fn target_col(&self) -> i32 {
+        match *self {
+            Kind::Amber => AMBER_COL,
+            Kind::Bronze => BRONZE_COL,
+            Kind::Copper => COPPER_COL,
+            Kind::Desert => DESERT_COL,
+        }
+    }
+
+    /// Non-zero code of the kind, so that zero can stand for an empty cell in a packed world.
+    fn code(&self) -> u128 {
+        match *self {
+            Kind::Amber => 1,
+            Kind::Bronze => 2,
+            Kind::Copper => 3,
+            Kind::Desert => 4,
+        }
+    }
+
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'A' => Some(Kind::Amber),
+            'B' => Some(Kind::Bronze),
+            'C' => Some(Kind::Copper),
+            'D' => Some(Kind::Desert),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+enum State {
+    Unmoved,
+    InHallway,
+    Done,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Amphipod {
+    row: i32,
+    col: i32,
+    kind: Kind,
+    state: State,
+}
+
+impl Amphipod {
+    fn new(row: i32, col: i32, kind: Kind) -> Self {
+        Amphipod {
+            row,
+            col,
+            kind,
+            state: State::Unmoved,
+        }
+    }
+
+    fn move_cost(&self) -> i64 {
+        self.kind.move_cost()
+    }
+
+    fn initial(&self) -> &'static str {
+        self.kind.initial()
+    }
+
+    fn target_col(&self) -> i32 {
+        self.kind.target_col()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct World {
+    amphipods: Vec<Amphipod>,
+    cost_so_far: i64,
+    height: usize,
+}
+
+impl World {
+    fn new(height: usize) -> Self {
+        World {
+            amphipods: Vec::new(),
+            cost_so_far: 0,
+            height,
+        }
+    }
+
+    /// Parses a burrow diagram like the one in the puzzle text. The rooms can be of any depth.
+    ///
+    /// The diagram is the world's render, shifted by one to account for the outer wall: the corridor is on the second
+    /// line, and the rooms are below it. Amphipods in the corridor (e.g., in a rendered mid-game world) are parsed as
+    /// being in the hallway, and the rest as unmoved.
+    fn parse_diagram(diagram: &str) -> Self {
+        let lines: Vec<&str> = diagram
+            .lines()
+            .map(|line| line.trim_end())
+            .filter(|line| !line.is_empty())
+            .collect();
+        assert!(
+            lines.len() >= 4,
+            "A burrow needs a wall, a corridor, at least one room row, and a floor."
+        );
+        assert_eq!(
+            lines[1].len(),
+            (END_COL + 3) as usize,
+            "Unexpected corridor width: {}",
+            lines[1]
+        );
+
+        // Everything between the corridor and the floor is a room row.
+        let mut world = World::new(lines.len() - 2);
+        for (line_idx, line) in lines.iter().enumerate() {
+            for (char_idx, ch) in line.chars().enumerate() {
+                if let Some(kind) = Kind::from_char(ch) {
+                    let row = line_idx as i32 - 1;
+                    let col = char_idx as i32 - 1;
+                    let mut amphipod = Amphipod::new(row, col, kind);
+                    if row == COR_ROW {
+                        assert!(
+                            OK_STOP_COL[col as usize],
+                            "Amphipods cannot stop in front of a room (col {})",
+                            col
+                        );
+                        amphipod.state = State::InHallway;
+                    }
+                    world.amphipods.push(amphipod);
+                }
+            }
+        }
+        world
+    }
+
+    /// Returns a copy of this (folded) world with the extra Part 2 rows inserted right below the upper-room row.
+    fn with_part_2_rows(&self) -> World {
+        let n_extra = PART_2_ROWS.len() as i32;
+        let mut unfolded = World::new(self.height + PART_2_ROWS.len());
+        for a in &self.amphipods {
+            let row = if a.row > UR_ROW {
+                a.row + n_extra
+            } else {
+                a.row
+            };
+            unfolded
+                .amphipods
+                .push(Amphipod::new(row, a.col, a.kind.clone()));
+        }
+        for (extra_idx, line) in PART_2_ROWS.iter().enumerate() {
+            for (char_idx, ch) in line.chars().enumerate() {
+                if let Some(kind) = Kind::from_char(ch) {
+                    let row = UR_ROW + 1 + extra_idx as i32;
+                    unfolded
+                        .amphipods
+                        .push(Amphipod::new(row, char_idx as i32 - 1, kind));
+                }
+            }
+        }
+        unfolded
+    }
+
+    /// Renders the burrow in the same format as the puzzle input, so that 'parse_diagram(render())' is a no-op.
+    fn render(&self) -> String {
+        let width = (END_COL + 3) as usize;
+        let mut out_arr: Vec<Vec<char>> = Vec::new();
+        out_arr.push(vec!['#'; width]);
+        out_arr.push(format!("#{}#", ".".repeat(width - 2)).chars().collect());
+        for room_row in UR_ROW..(self.height as i32) {
+            // Only the upper room row is flanked by walls, the ones below it are indented instead.
+            let line = if room_row == UR_ROW {
+                "###.#.#.#.###"
+            } else {
+                "  #.#.#.#.#"
+            };
+            out_arr.push(line.chars().collect());
+        }
+        out_arr.push("  #########".chars().collect());
+
+        for a in &self.amphipods {
+            let ch = a.initial().chars().next().unwrap();
+            out_arr[(a.row + 1) as usize][(a.col + 1) as usize] = ch;
+        }
+
+        out_arr
+            .iter()
+            .map(|line| line.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// Encodes where every kind of amphipod is, which is all that matters for the rest of the search, into a single
+    /// integer, which is much cheaper to hash and compare than the list of amphipods.
+    ///
+    /// Amphipod states are not encoded, since they follow from the positions: amphipods in the corridor are in the
+    /// hallway, and the ones in a room either still have to move out, or are done and can never move out again.
+    fn packed(&self) -> u128 {
+        let mut packed = 0u128;
+        for a in &self.amphipods {
+            let cell = if a.row == COR_ROW {
+                a.col as usize
+            } else {
+                let room = ((a.col - AMBER_COL) / 2) as usize;
+                (END_COL as usize + 1) + room * (self.height - 1) + (a.row - UR_ROW) as usize
+            };
+            packed |= a.kind.code() << (cell * BITS_PER_CELL);
+        }
+        packed
+    }
+
+    /// Returns whether the amphipod is in its target room, with only amphipods of the same kind below it.
+    fn is_settled(&self, a: &Amphipod) -> bool {
+        a.row > COR_ROW
+            && a.col == a.target_col()
+            && ((a.row + 1)..(self.height as i32))
+                .all(|row| !self.is_free(row, a.col) && self.at(row, a.col).kind == a.kind)
+    }
+
+    /// A lower bound on the energy needed to finish, ignoring all the other amphipods: everyone not settled yet needs to
+    /// walk (at least) to their room's entrance and take one step in.
+    fn min_remaining_cost(&self) -> i64 {
+        self.amphipods
+            .iter()
+            .filter(|a| !self.is_settled(a))
+            .map(|a| {
+                let n_hor_steps = if a.col == a.target_col() {
+                    // Blocking someone else in our own room, so we need to step aside and come back.
+                    2
+                } else {
+                    (a.col - a.target_col()).abs()
+                };
+                (a.row + n_hor_steps + 1) as i64 * a.move_cost()
+            })
+            .sum()
+    }
+
+    fn is_solved(&self) -> bool {
+        self.amphipods
+            .iter()
+            .all(|a| a.row > COR_ROW && a.col == a.target_col())
+    }
+
+    fn at(&self, row: i32, col: i32) -> &Amphipod {
+        self.amphipods
+            .iter()
+            .find(|&a| a.row == row && a.col == col)
+            .expect("invalid at(), no amphipod found!!")
+    }
+
+    fn with_move(
+        &self,
+        a_idx: usize,
+        new_row: i32,
+        new_col: i32,
+        move_cost: i64,
+        new_state: State,
+    ) -> World {
+        let mut cp = World::clone(self);
+        cp.amphipods[a_idx].row = new_row;
+        cp.amphipods[a_idx].col = new_col;
+        cp.amphipods[a_idx].state = new_state;
+        cp.cost_so_far += move_cost;
+        cp
+    }
+
+    fn is_free(&self, row: i32, col: i32) -> bool {
+        !self.amphipods.iter().any(|a| a.row == row && a.col == col)
+    }
+
+    /// Returns all valid non-end-goal moves for the unmoved amphipod at the given index, with associated cost.
+    fn valid_moves(&self, a_idx: usize) -> Vec<(i32, i32, State, i64)> {
+        let mut moves = Vec::new();
+        let amphipod = &self.amphipods[a_idx];
+        if amphipod.state != State::Unmoved {
+            panic!("Attempted to move a hallway/finished amphipod!");
+        }
+
+        if amphipod.col == amphipod.target_col() {
+            // We are on the right column
+            if (amphipod.row as usize) == self.height - 1usize {
+                // We are on the bottom row, with nobody to block. We're done.
+                return vec![(amphipod.row, amphipod.col, State::Done, 0i64)];
+            } else {
+                // We are on a non-bottom row
+                // If the amphipod(s) below us are all the same kind, we are done, otherwise, continue the algorithm
+                // since we will need to try to move out.
+
+                let mut homogeneous = true;
+                for row in (amphipod.row + 1)..(self.height as i32) {
+                    let neighbor = self.at(row, amphipod.col);
+                    if neighbor.kind != amphipod.kind {
+                        homogeneous = false;
+                        break;
+                    }
+                }
+                if homogeneous {
+                    return vec![(amphipod.row, amphipod.col, State::Done, 0i64)];
+                }
+            }
+        }
+
+        if !self.is_free(amphipod.row - 1, amphipod.col) {
+            // We are blocked-in.
+            return moves;
+        }
+
+        // Not blocked in. Directly above is not an allowed move, but let's try above-and-left-ward moves...
+        for col in (0..amphipod.col).rev() {
+            if self.is_free(COR_ROW, col) {
+                if OK_STOP_COL[col as usize] {
+                    let n_hor_steps = (amphipod.col - col) as i64;
+                    let n_ver_steps = amphipod.row as i64;
+                    let cost_to_left = (n_hor_steps + n_ver_steps) * amphipod.move_cost();
+                    moves.push((COR_ROW, col, State::InHallway, cost_to_left));
+                }
+            } else {
+                break;
+            }
+        }
+        // ...and right-ward moves
+        for col in amphipod.col + 1..=END_COL {
+            if self.is_free(COR_ROW, col) {
+                if OK_STOP_COL[col as usize] {
+                    let n_hor_steps = (col - amphipod.col) as i64;
+                    let n_ver_steps = amphipod.row as i64;
+                    let cost_to_right = (n_hor_steps + n_ver_steps) * amphipod.move_cost();
+                    moves.push((COR_ROW, col, State::InHallway, cost_to_right));
+                }
+            } else {
+                break;
+            }
+        }
+
+        moves
+    }
+
+    /// Returns all valid end-goal moves for the in-hallway amphipod at the given index.
+    fn valid_parking_spots(&self, a_idx: usize) -> Vec<(i32, i32, i64)> {
+        let mut moves = Vec::new();
+        let amphipod = &self.amphipods[a_idx];
+        if amphipod.state != State::InHallway {
+            panic!("Attempted to park an unmoved/finished amphipod!");
+        }
+
+        let target_col = amphipod.target_col();
+
+        // Find the bottom-most slot in the target room.
+        // If all occupied slots under _that_ one are the right kind, move in, otherwise do nothing.
+        let mut target_row = -1i32;
+        for r in 1i32..(self.height as i32) {
+            if !self.is_free(r, target_col) {
+                target_row = r - 1;
+                break;
+            }
+        }
+        if target_row == 0 {
+            // Can't get into the target room, no action to do.
+            return Vec::new();
+        }
+        if target_row == -1i32 {
+            // All rows are free, set the bottom one as a target
+            target_row = (self.height as i32) - 1;
+        }
+
+        let mut homogeneous = true;
+        for r in target_row + 1i32..(self.height as i32) {
+            if self.at(r, target_col).kind != amphipod.kind {
+                homogeneous = false;
+                break;
+            }
+        }
+
+        if !homogeneous {
+            return Vec::new();
+        }
+
+        // At this point, we've established the room is fine for us to go in, but now we need to check if we can
+        // actually get to its entrance!
+        let mut can_path = true;
+        if target_col > amphipod.col {
+            // check right move
+            for col in amphipod.col + 1..=target_col {
+                if !self.is_free(COR_ROW, col) {
+                    can_path = false;
+                    break;
+                }
+            }
+        } else {
+            // check left move
+            for col in target_col..amphipod.col {
+                if !self.is_free(COR_ROW, col) {
+                    can_path = false;
+                    break;
+                }
+            }
+        }
+
+        if can_path {
+            let n_hor_steps = ((target_col - amphipod.col).abs()) as i64;
+            let n_ver_steps = target_row as i64;
+            let cost = (n_hor_steps + n_ver_steps) * amphipod.move_cost();
+            moves.push((target_row, target_col, cost));
+        }
+
+        moves
+    }
+
+    /// Returns a vector of ALL possible worlds that can result from amphipod moves in the current world.
+    fn moves(&self) -> Vec<World> {
+        let mut new_worlds = Vec::new();
+
+        for a_idx in 0..self.amphipods.len() {
+            let amphipod = &self.amphipods[a_idx];
+            match amphipod.state {
+                State::Done => {}
+                State::Unmoved => {
+                    for (mv_row, mv_col, mv_state, mv_cost) in self.valid_moves(a_idx) {
+                        let new_world = self.with_move(a_idx, mv_row, mv_col, mv_cost, mv_state);
+                        new_worlds.push(new_world);
+                    }
+                }
+                State::InHallway => {
+                    for (mv_row, mv_col, mv_cost) in self.valid_parking_spots(a_idx) {
+                        let new_world = self.with_move(a_idx, mv_row, mv_col, mv_cost, State::Done);
+                        new_worlds.push(new_world);
+                    }
+                }
+            }
+        }
+
+        new_worlds
+    }
+}
+
+fn print_world(world: &World) {
+    println!("{}", world.render());
+    println!("Cost: {}", world.cost_so_far);
+}
+
+/// Replays a solution found by 'solve_world_with_path', one move at a time.
+fn print_solution(path: &[World]) {
+    for (step, world) in path.iter().enumerate() {
+        println!("Step {}:", step);
+        print_world(world);
+        println!();
+    }
+}
+
+/// Returns the minimum total energy needed to organize the amphipods in 'initial_world', together with every world
+/// along the way, starting from 'initial_world' itself. Returns 'None' if the amphipods cannot be organized.
+fn solve_world_with_path(initial_world: &World) -> Option<(i64, Vec<World>)> {
+    assert!(
+        (END_COL as usize + 1 + 4 * (initial_world.height - 1)) * BITS_PER_CELL <= 128,
+        "The rooms are too deep to pack the world into a u128."
+    );
+
+    // All the worlds we have generated, with the index of the world they were reached from, so we can retrace the
+    // optimal path at the end.
+    let mut worlds: Vec<(World, Option<usize>)> = vec![(initial_world.clone(), None)];
+    let mut best_cost: HashMap<u128, i64> = HashMap::new();
+    best_cost.insert(initial_world.packed(), initial_world.cost_so_far);
+    // Min-heap of (cost so far + lower bound of the remaining cost, index of the world).
+    let mut frontier = BinaryHeap::new();
+    frontier.push(Reverse((initial_world.min_remaining_cost(), 0usize)));
+
+    while let Some(Reverse((_, world_idx))) = frontier.pop() {
+        let world = &worlds[world_idx].0;
+        let packed_world = world.packed();
+        if best_cost[&packed_world] < world.cost_so_far {
+            // We found a cheaper way to get here after queueing this one.
+            continue;
+        }
+        if world.is_solved() {
+            let cost = world.cost_so_far;
+            let mut path = Vec::new();
+            let mut cur = Some(world_idx);
+            while let Some(idx) = cur {
+                let (world, parent) = &worlds[idx];
+                path.push(world.clone());
+                cur = *parent;
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for next in world.moves() {
+            let packed = next.packed();
+            // Marking an amphipod as done is free, and does not change the burrow. It is also pointless, since an
+            // amphipod in that spot never moves out anyway, so we skip these non-moves.
+            if packed == packed_world
+                || best_cost
+                    .get(&packed)
+                    .is_some_and(|&best| best <= next.cost_so_far)
+            {
+                continue;
+            }
+            best_cost.insert(packed, next.cost_so_far);
+            frontier.push(Reverse((
+                next.cost_so_far + next.min_remaining_cost(),
+                worlds.len(),
+            )));
+            worlds.push((next, Some(world_idx)));
+        }
+    }
+
+    None
+}
+
+/// Returns the minimum total energy needed to organize the amphipods in 'initial_world'.
+fn solve_world(initial_world: &World) -> i64 {
+    solve_world_with_path(initial_world)
+        .expect("The amphipods cannot be organized.")
+        .0
+}
+
+/// Expands every world generation by generation, deduplicating them by their amphipods, until no new worlds show up.
+/// Much slower than A*, but simple enough to check it against.
+#[cfg(test)]
+fn solve_world_bfs(initial_world: &World) -> i64 {
+    let mut worlds = vec![initial_world.clone()];
+    let mut min_cost: i64 = i64::MAX;
+    let max_generations = 50;
+    for _generation in 0..max_generations {
+        let mut new_worlds = Vec::new();
+        let mut amphi_to_cost: HashMap<Vec<Amphipod>, i64> = HashMap::new();
+
+        for w in &worlds {
+            if w.is_solved() {
+                if w.cost_so_far <= min_cost {
+                    min_cost = w.cost_so_far;
+                }
+                continue;
+            }
+            let moves = &mut w.moves();
+            new_worlds.append(moves);
+        }
+
+        for nw in &mut new_worlds {
+            if amphi_to_cost.contains_key(&nw.amphipods) {
+                if amphi_to_cost.get(&nw.amphipods).unwrap() > &nw.cost_so_far {
+                    amphi_to_cost.insert(nw.amphipods.clone(), nw.cost_so_far);
+                }
+            } else {
+                amphi_to_cost.insert(nw.amphipods.clone(), nw.cost_so_far);
+            }
+        }
+
+        if amphi_to_cost.is_empty() {
+            break;
+        }
+
+        worlds.clear();
+        for el in amphi_to_cost {
+            worlds.push(World {
+                height: initial_world.height,
+                amphipods: el.0,
+                cost_so_far: el.1,
+            });
+        }
+    }
+
+    min_cost
+}
+
+/// Returns the worlds for both parts: the diagram as-is, and the diagram unfolded with the extra Part 2 rows.
+pub fn parse(input: &str) -> (World, World) {
+    let world = World::parse_diagram(input);
+    let unfolded = world.with_part_2_rows();
+    (world, unfolded)
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+/// Prints every move of the optimal solution to both parts, e.g., for 'aoc21 run 23 --show-path'.
+pub fn show_path(input: &str) {
+    let (part_1_world, part_2_world) = parse(input);
+    for (part, world) in [(1, &part_1_world), (2, &part_2_world)] {
+        println!("Part {}:", part);
+        match solve_world_with_path(world) {
+            Some((_, path)) => print_solution(&path),
+            None => println!("The amphipods cannot be organized."),
+        }
+    }
+}
+
+pub fn solve_parsed((part_1_world, part_2_world): &(World, World)) -> (Answer, Answer) {
+    let part_1 = solve_world(part_1_world);
+    let part_2 = solve_world(part_2_world);
+
+    (part_1.into(), part_2.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO: &str = "\
+#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn test_parse_render_round_trip() {
+        let world = World::parse_diagram(DEMO);
+        assert_eq!(world.height, 3);
+        assert_eq!(world.amphipods.len(), 8);
+        assert_eq!(world.render(), DEMO);
+
+        // Amphipods in the corridor and empty room slots survive the round trip, too.
+        let mid_game = "\
+#############
+#.....D.D.A.#
+###.#B#C#.###
+  #A#B#C#.#
+  #########";
+        let world = World::parse_diagram(mid_game);
+        assert_eq!(world.render(), mid_game);
+        assert_eq!(
+            world
+                .amphipods
+                .iter()
+                .filter(|a| a.state == State::InHallway)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_part_2_rows() {
+        let unfolded = World::parse_diagram(DEMO).with_part_2_rows();
+        let expected = "\
+#############
+#...........#
+###B#C#B#D###
+  #D#C#B#A#
+  #D#B#A#C#
+  #A#D#C#A#
+  #########";
+        assert_eq!(unfolded.height, 5);
+        assert_eq!(unfolded.render(), expected);
+        assert_eq!(World::parse_diagram(expected).render(), expected);
+    }
+
+    #[test]
+    fn test_optimal_path() {
+        let world = World::parse_diagram(DEMO);
+        let (cost, path) = solve_world_with_path(&world).unwrap();
+        assert_eq!(cost, 12521);
+        assert!(world.min_remaining_cost() <= cost);
+
+        assert_eq!(path[0].render(), DEMO);
+        assert!(path.last().unwrap().is_solved());
+        assert_eq!(path.last().unwrap().cost_so_far, cost);
+        for step in path.windows(2) {
+            // Every step moves exactly one amphipod, and costs something.
+            let n_moved = step[0]
+                .amphipods
+                .iter()
+                .zip(&step[1].amphipods)
+                .filter(|(before, after)| (before.row, before.col) != (after.row, after.col))
+                .count();
+            assert_eq!(n_moved, 1);
+            assert!(step[1].cost_so_far > step[0].cost_so_far);
+        }
+    }
+
+    #[test]
+    fn test_matches_bfs() {
+        // Close enough to solved for the old search to finish quickly in debug builds.
+        let world = World::parse_diagram(
+            "\
+#############
+#...........#
+###B#A#C#D###
+  #A#B#C#D#
+  #########",
+        );
+        assert_eq!(solve_world(&world), 46);
+        assert_eq!(solve_world(&world), solve_world_bfs(&world));
+    }
+
+    #[test]
+    fn test_arbitrary_depth() {
+        let deep = "\
+#############
+#...........#
+###A#B#C#D###
+  #A#B#C#D#
+  #A#B#C#D#
+  #A#B#C#D#
+  #A#B#C#D#
+  #A#B#C#D#
+  #########";
+        let world = World::parse_diagram(deep);
+        assert_eq!(world.height, 7);
+        assert!(world.is_solved());
+        assert_eq!(world.render(), deep);
+        assert_eq!(solve_world(&world), 0);
+    }
+}
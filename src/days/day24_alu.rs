@@ -0,0 +1,1460 @@
+//! 2021 AoC Day 24: Arithmetic Logic Unit
+//!
+//! Find the largest and smallest 14-digit values which make a custom assembly program output zero.
+//!
+//! The trick was to not naively iterate over all possible inputs to find the min/max accepted ones, as this would have
+//! taken multiple days, even with a very fast Rust implementation.
+//!
+//! Instead, I observed that the given ALU program actually decomposes into 14 similar chunks, one for each input digit,
+//! and each chunk actually takes just two inputs - the digit and the current value of the ALU's 'z' register. This
+//! allowed a much faster solution by only running a chunk of the program on the cross-product of possible 'z' and digit
+//! inputs. Since the range of 'z' values which can be output by a chunk is (sort of) bounded, this made the search much
+//! more efficient, though still slow - the program takes ~10 minutes to find a solution. Perhaps we can figure out an
+//! even better way of filtering a chunk's valid 'z' inputs.
+//!
+//! Looking at hints from other people on Reddit, it seems it may be beneficial to start from the end. The 14-chunk
+//! observation is definitely 100% a correct insight. I also wonder whether we can chunk the program differently, so as
+//! to exploit the modulo operations better. There's probably also a higher-level meaning to the 'z' register.
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::thread;
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::answer::Answer;
+
+const REGISTER_NAMES: [char; 4] = ['w', 'x', 'y', 'z'];
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Expr {
+    LITERAL(i64),
+    REG(char),
+}
+
+impl Expr {
+    fn from(input: &str) -> Result<Expr, String> {
+        match i64::from_str(input) {
+            Ok(val) => Ok(Expr::LITERAL(val)),
+            Err(_)
+                if input.len() == 1 && REGISTER_NAMES.contains(&input.chars().next().unwrap()) =>
+            {
+                Ok(Expr::REG(input.chars().next().unwrap()))
+            }
+            Err(_) => Err(format!("Invalid operand: {}", input)),
+        }
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ALUInstruction {
+    INP(Expr),
+    ADD(Expr, Expr),
+    MUL(Expr, Expr),
+    DIV(Expr, Expr),
+    MOD(Expr, Expr),
+    EQL(Expr, Expr),
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::LITERAL(val) => write!(f, "{}", val),
+            Expr::REG(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Formats the instruction the same way as in the puzzle input.
+impl fmt::Display for ALUInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, a, b) = match self {
+            ALUInstruction::INP(a) => return write!(f, "inp {}", a),
+            ALUInstruction::ADD(a, b) => ("add", a, b),
+            ALUInstruction::MUL(a, b) => ("mul", a, b),
+            ALUInstruction::DIV(a, b) => ("div", a, b),
+            ALUInstruction::MOD(a, b) => ("mod", a, b),
+            ALUInstruction::EQL(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", name, a, b)
+    }
+}
+
+fn parse_alu_instruction(raw: &str) -> Result<ALUInstruction, String> {
+    let parts: Vec<&str> = raw.split_whitespace().collect();
+    let n_operands = if parts.first() == Some(&"inp") { 1 } else { 2 };
+    if parts.len() != n_operands + 1 {
+        return Err(format!("Wrong number of operands: {}", raw));
+    }
+    // The first operand is where the result goes, so it can't be a literal.
+    let target = match Expr::from(parts[1])? {
+        Expr::LITERAL(_) => return Err(format!("Cannot write to a literal: {}", raw)),
+        target => target,
+    };
+    if n_operands == 1 {
+        return Ok(ALUInstruction::INP(target));
+    }
+
+    let source = Expr::from(parts[2])?;
+    match parts[0] {
+        "add" => Ok(ALUInstruction::ADD(target, source)),
+        "mul" => Ok(ALUInstruction::MUL(target, source)),
+        "div" => Ok(ALUInstruction::DIV(target, source)),
+        "mod" => Ok(ALUInstruction::MOD(target, source)),
+        "eql" => Ok(ALUInstruction::EQL(target, source)),
+        _ => Err(format!("Invalid instruction name: {}", parts[0])),
+    }
+}
+
+/// A parsed ALU program.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Program {
+    pub instructions: Vec<ALUInstruction>,
+}
+
+/// Parses the puzzle's assembly. Blank lines, '---' separators and '#' comments (like in 'input/24-commented.txt') are
+/// ignored.
+impl FromStr for Program {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let mut instructions = Vec::new();
+        for (line_idx, line) in raw.lines().enumerate() {
+            let code = line.split('#').next().unwrap().trim();
+            if code.is_empty() || code.chars().all(|ch| ch == '-') {
+                continue;
+            }
+            let instruction = parse_alu_instruction(code)
+                .map_err(|err| format!("Line {}: {}", line_idx + 1, err))?;
+            instructions.push(instruction);
+        }
+        Ok(Program { instructions })
+    }
+}
+
+/// Things which make the ALU crash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AluError {
+    DivByZero {
+        pc: usize,
+    },
+    /// The puzzle does not define 'mod a b' for 'b <= 0', either.
+    InvalidModulus {
+        pc: usize,
+    },
+    /// 'mod a b' with 'a < 0'.
+    NegativeMod {
+        pc: usize,
+    },
+    /// An 'inp' instruction ran after all the input was used up.
+    OutOfInput {
+        pc: usize,
+    },
+    /// The instruction writes to a literal, or names a register the ALU does not have. The parser rejects both, but
+    /// instructions can also be built by hand.
+    InvalidOperand {
+        pc: usize,
+        instruction: ALUInstruction,
+    },
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AluError::DivByZero { pc } => write!(f, "Division by zero at instruction {}", pc),
+            AluError::InvalidModulus { pc } => {
+                write!(f, "Modulo by a non-positive number at instruction {}", pc)
+            }
+            AluError::NegativeMod { pc } => {
+                write!(f, "Modulo of a negative number at instruction {}", pc)
+            }
+            AluError::OutOfInput { pc } => write!(f, "Ran out of input at instruction {}", pc),
+            AluError::InvalidOperand { pc, instruction } => {
+                write!(
+                    f,
+                    "Invalid operand in '{}' at instruction {}",
+                    instruction, pc
+                )
+            }
+        }
+    }
+}
+
+/// The ALU: four registers, and a count of how many instructions it executed so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Alu {
+    pub registers: [i64; 4],
+    /// Index of the next instruction, counting from where the ALU started.
+    pub pc: usize,
+}
+
+fn find_reg(name: char) -> Option<usize> {
+    REGISTER_NAMES.iter().position(|&reg| reg == name)
+}
+
+fn reg_idx(name: char) -> usize {
+    find_reg(name).unwrap_or_else(|| panic!("Invalid register: {}", name))
+}
+
+impl Alu {
+    pub fn new() -> Self {
+        Alu::default()
+    }
+
+    /// An ALU whose 'z' register starts at 'z', e.g., to run just one digit's block of the program.
+    pub fn with_z(z: i64) -> Self {
+        let mut alu = Alu::new();
+        alu.registers[reg_idx('z')] = z;
+        alu
+    }
+
+    /// Panics if 'name' is not one of 'w', 'x', 'y' or 'z'.
+    pub fn reg(&self, name: char) -> i64 {
+        self.registers[reg_idx(name)]
+    }
+
+    /// Returns 'None' for an unknown register.
+    fn eval(&self, expr: &Expr) -> Option<i64> {
+        match expr {
+            Expr::LITERAL(val) => Some(*val),
+            Expr::REG(name) => find_reg(*name).map(|reg| self.registers[reg]),
+        }
+    }
+
+    /// Returns 'None' if the instruction writes to a literal or to an unknown register.
+    fn target(instruction: &ALUInstruction) -> Option<usize> {
+        match instruction {
+            ALUInstruction::INP(Expr::REG(name))
+            | ALUInstruction::ADD(Expr::REG(name), _)
+            | ALUInstruction::MUL(Expr::REG(name), _)
+            | ALUInstruction::DIV(Expr::REG(name), _)
+            | ALUInstruction::MOD(Expr::REG(name), _)
+            | ALUInstruction::EQL(Expr::REG(name), _) => find_reg(*name),
+            _ => None,
+        }
+    }
+
+    /// Executes a single instruction, reading from 'input' if it is an 'inp'.
+    pub fn step(
+        &mut self,
+        instruction: &ALUInstruction,
+        input: &mut impl Iterator<Item = i64>,
+    ) -> Result<(), AluError> {
+        let pc = self.pc;
+        let invalid = AluError::InvalidOperand {
+            pc,
+            instruction: *instruction,
+        };
+        let target = Alu::target(instruction).ok_or(invalid)?;
+        let eval = |src: &Expr| self.eval(src).ok_or(invalid);
+        let a = self.registers[target];
+        self.registers[target] = match instruction {
+            ALUInstruction::INP(_) => input.next().ok_or(AluError::OutOfInput { pc })?,
+            ALUInstruction::ADD(_, src) => a + eval(src)?,
+            ALUInstruction::MUL(_, src) => a * eval(src)?,
+            ALUInstruction::DIV(_, src) => match eval(src)? {
+                0 => return Err(AluError::DivByZero { pc }),
+                // Rust's integer division truncates towards zero, exactly like the ALU.
+                b => a / b,
+            },
+            ALUInstruction::MOD(_, src) => match eval(src)? {
+                b if b <= 0 => return Err(AluError::InvalidModulus { pc }),
+                _ if a < 0 => return Err(AluError::NegativeMod { pc }),
+                b => a % b,
+            },
+            ALUInstruction::EQL(_, src) => i64::from(a == eval(src)?),
+        };
+        self.pc += 1;
+        Ok(())
+    }
+
+    /// Executes all the 'instructions', reading their input from 'input'.
+    pub fn run(&mut self, instructions: &[ALUInstruction], input: &[i64]) -> Result<(), AluError> {
+        let mut input = input.iter().copied();
+        for instruction in instructions {
+            self.step(instruction, &mut input)?;
+        }
+        Ok(())
+    }
+
+    /// Like 'run', but also returns the registers after every instruction, for debugging.
+    pub fn trace(
+        &mut self,
+        instructions: &[ALUInstruction],
+        input: &[i64],
+    ) -> Result<Vec<[i64; 4]>, AluError> {
+        let mut input = input.iter().copied();
+        let mut states = Vec::with_capacity(instructions.len());
+        for instruction in instructions {
+            self.step(instruction, &mut input)?;
+            states.push(self.registers);
+        }
+        Ok(states)
+    }
+}
+
+/// An inclusive range of values a register may hold.
+///
+/// Bounds are computed with i128s and clamped to the i64 range, which is sound as long as the ALU itself never
+/// overflows an i64 (which the puzzle never does).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub lo: i64,
+    pub hi: i64,
+}
+
+/// The digits of a model number.
+const DIGIT_RANGE: Interval = Interval { lo: 1, hi: 9 };
+
+impl Interval {
+    pub fn new(lo: i64, hi: i64) -> Self {
+        assert!(lo <= hi, "Empty interval [{}, {}]", lo, hi);
+        Interval { lo, hi }
+    }
+
+    pub fn constant(val: i64) -> Self {
+        Interval::new(val, val)
+    }
+
+    pub fn contains(&self, val: i64) -> bool {
+        self.lo <= val && val <= self.hi
+    }
+
+    fn as_constant(&self) -> Option<i64> {
+        if self.lo == self.hi {
+            Some(self.lo)
+        } else {
+            None
+        }
+    }
+
+    /// The smallest interval containing all the given (i128) values.
+    fn hull(vals: &[i128]) -> Self {
+        let clamp = |val: i128| val.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+        Interval::new(
+            clamp(*vals.iter().min().unwrap()),
+            clamp(*vals.iter().max().unwrap()),
+        )
+    }
+
+    fn corners(&self, other: &Interval, op: impl Fn(i128, i128) -> i128) -> Self {
+        let (a, b) = (
+            (self.lo as i128, self.hi as i128),
+            (other.lo as i128, other.hi as i128),
+        );
+        Interval::hull(&[op(a.0, b.0), op(a.0, b.1), op(a.1, b.0), op(a.1, b.1)])
+    }
+
+    fn add(&self, other: &Interval) -> Self {
+        self.corners(other, |a, b| a + b)
+    }
+
+    fn mul(&self, other: &Interval) -> Self {
+        self.corners(other, |a, b| a * b)
+    }
+
+    /// Returns 'None' if the division is invalid for every possible divisor.
+    fn div(&self, other: &Interval) -> Option<Self> {
+        // Division by zero crashes the ALU, so only the non-zero divisors matter. Truncating division is monotonic in
+        // the dividend, and, for a fixed sign, in the divisor, so the extremes are at the corners of each half.
+        let mut vals = Vec::new();
+        for (lo, hi) in [(other.lo, other.hi.min(-1)), (other.lo.max(1), other.hi)] {
+            if lo <= hi {
+                let half = Interval::new(lo, hi).corners(self, |b, a| a / b);
+                vals.extend([half.lo as i128, half.hi as i128]);
+            }
+        }
+        if vals.is_empty() {
+            None
+        } else {
+            Some(Interval::hull(&vals))
+        }
+    }
+
+    /// Returns 'None' if the modulo is invalid for every possible operand.
+    fn modulo(&self, other: &Interval) -> Option<Self> {
+        // Only non-negative dividends and positive divisors are valid.
+        if self.hi < 0 || other.hi < 1 {
+            return None;
+        }
+        let a = Interval::new(self.lo.max(0), self.hi);
+        let b_max = other.hi;
+        match other.as_constant() {
+            Some(b) if a.hi - a.lo < b && a.lo % b <= a.hi % b => {
+                Some(Interval::new(a.lo % b, a.hi % b))
+            }
+            // The result can never exceed the dividend, either.
+            _ => Some(Interval::new(0, (b_max - 1).min(a.hi))),
+        }
+    }
+
+    fn eql(&self, other: &Interval) -> Self {
+        if self.hi < other.lo || other.hi < self.lo {
+            Interval::constant(0)
+        } else if self.as_constant().is_some() && self.as_constant() == other.as_constant() {
+            Interval::constant(1)
+        } else {
+            Interval::new(0, 1)
+        }
+    }
+}
+
+/// What 'analyze' found out about a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    /// The range of 'z' right before each 'inp' instruction.
+    pub z_at_input: Vec<Interval>,
+    /// The 'eql' instructions whose outcome does not depend on the input, as (index, result) pairs. Their results
+    /// are tracked as constants, so the analysis does not lose precision on the branches which can never be taken.
+    pub constant_eqls: Vec<(usize, i64)>,
+    /// The range of each register at the end of the program, or 'None' if the program always crashes.
+    pub registers: Option<[Interval; 4]>,
+}
+
+/// Runs the program on intervals rather than values, assuming every input is a digit in 1..=9, and the registers start
+/// out in the given ranges. Panics on instructions the parser would reject, like writes to a literal.
+pub fn analyze(instructions: &[ALUInstruction], initial: [Interval; 4]) -> Analysis {
+    let mut registers = initial;
+    let mut z_at_input = Vec::new();
+    let mut constant_eqls = Vec::new();
+    let eval = |registers: &[Interval; 4], expr: &Expr| match expr {
+        Expr::LITERAL(val) => Interval::constant(*val),
+        Expr::REG(name) => registers[reg_idx(*name)],
+    };
+
+    for (pc, instruction) in instructions.iter().enumerate() {
+        let target = Alu::target(instruction)
+            .unwrap_or_else(|| panic!("Invalid instruction format: {:?}", instruction));
+        let a = registers[target];
+        let result = match instruction {
+            ALUInstruction::INP(_) => {
+                z_at_input.push(registers[reg_idx('z')]);
+                Some(DIGIT_RANGE)
+            }
+            ALUInstruction::ADD(_, src) => Some(a.add(&eval(&registers, src))),
+            ALUInstruction::MUL(_, src) => Some(a.mul(&eval(&registers, src))),
+            ALUInstruction::DIV(_, src) => a.div(&eval(&registers, src)),
+            ALUInstruction::MOD(_, src) => a.modulo(&eval(&registers, src)),
+            ALUInstruction::EQL(_, src) => {
+                let result = a.eql(&eval(&registers, src));
+                if let Some(val) = result.as_constant() {
+                    constant_eqls.push((pc, val));
+                }
+                Some(result)
+            }
+        };
+        match result {
+            Some(result) => registers[target] = result,
+            None => {
+                return Analysis {
+                    z_at_input,
+                    constant_eqls,
+                    registers: None,
+                }
+            }
+        }
+    }
+
+    Analysis {
+        z_at_input,
+        constant_eqls,
+        registers: Some(registers),
+    }
+}
+
+/// Returns whether the program can possibly end with 'z == 0' when the registers start out anywhere in the given
+/// ranges. 'false' is definitive, but 'true' just means the analysis could not rule it out.
+pub fn may_reach_zero(instructions: &[ALUInstruction], registers: [Interval; 4]) -> bool {
+    analyze(instructions, registers)
+        .registers
+        .is_some_and(|registers| registers[reg_idx('z')].contains(0))
+}
+
+/// The index of every 'inp' instruction in the program.
+fn input_positions(instructions: &[ALUInstruction]) -> Vec<usize> {
+    instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| matches!(instruction, ALUInstruction::INP(_)))
+        .map(|(pc, _)| pc)
+        .collect()
+}
+
+/// Splits the program into one block per digit, each starting at its 'inp', and returns them with the index of their
+/// first instruction. Whatever comes before the first 'inp' runs as part of the first block.
+fn split_digit_blocks(instructions: &[ALUInstruction]) -> Vec<(usize, &[ALUInstruction])> {
+    let positions = input_positions(instructions);
+    let mut starts = positions.clone();
+    if let Some(first) = starts.first_mut() {
+        *first = 0;
+    }
+    starts
+        .iter()
+        .zip(starts.iter().skip(1).chain([&instructions.len()]))
+        .map(|(&start, &end)| (start, &instructions[start..end]))
+        .collect()
+}
+
+/// For every 'inp' in the program, returns the largest (non-negative) 'z' right after the corresponding digit was
+/// processed, i.e., right before the next 'inp' (or at the very end), from which the rest of the program may still
+/// end with 'z == 0'. Any search can safely drop the states with a larger 'z'.
+///
+/// Interval arithmetic is inclusion-monotonic, so if starting anywhere in [z, z_max] cannot reach zero, starting
+/// anywhere in a smaller [z', z_max] cannot either, which means we can binary search for the cutoff.
+pub fn max_viable_z(instructions: &[ALUInstruction]) -> Vec<i64> {
+    let zero = Interval::constant(0);
+    let z_at_input = analyze(instructions, [zero; 4]).z_at_input;
+    let input_positions = input_positions(instructions);
+
+    let mut bounds = Vec::with_capacity(input_positions.len());
+    for digit_idx in 0..input_positions.len() {
+        let (suffix, z_max) = match input_positions.get(digit_idx + 1) {
+            Some(&pc) => (&instructions[pc..], z_at_input[digit_idx + 1].hi.max(0)),
+            None => {
+                // After the last digit, whatever 'z' is left is the program's output.
+                bounds.push(0);
+                continue;
+            }
+        };
+        // The other registers carry over from the earlier digits, too, so the suffix starts from their ranges.
+        let prefix = &instructions[..instructions.len() - suffix.len()];
+        let mut registers = match analyze(prefix, [zero; 4]).registers {
+            Some(registers) => registers,
+            None => {
+                // The program always crashes before getting here, so no 'z' is viable.
+                bounds.push(-1);
+                continue;
+            }
+        };
+
+        // Find the smallest 'lo' such that no 'z' in [lo, z_max] can reach zero. Everything below it might.
+        let (mut lo, mut hi) = (0i64, z_max + 1);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            registers[reg_idx('z')] = Interval::new(mid, z_max);
+            if may_reach_zero(suffix, registers) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        bounds.push(lo - 1);
+    }
+    bounds
+}
+
+/// An operand of a compiled instruction. Register operands whose value is known at compile time become immediates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Reg(usize),
+    Imm(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Inp,
+    /// Overwrites the register, e.g., 'mul x 0' followed by 'add x z' becomes a single copy of 'z' into 'x'.
+    Set(Operand),
+    Add(Operand),
+    Mul(Operand),
+    Div(Operand),
+    Mod(Operand),
+    Eql(Operand),
+}
+
+impl Op {
+    fn source(&self) -> Option<Operand> {
+        match *self {
+            Op::Inp => None,
+            Op::Set(src)
+            | Op::Add(src)
+            | Op::Mul(src)
+            | Op::Div(src)
+            | Op::Mod(src)
+            | Op::Eql(src) => Some(src),
+        }
+    }
+
+    /// Whether the op also reads its target register, rather than just overwriting it.
+    fn reads_target(&self) -> bool {
+        !matches!(self, Op::Inp | Op::Set(_))
+    }
+
+    /// Whether dropping the op could hide an error (or, for 'inp', change which digit later 'inp's read).
+    fn has_side_effects(&self) -> bool {
+        matches!(self, Op::Inp | Op::Div(_) | Op::Mod(_))
+    }
+}
+
+/// One compiled instruction: what it does, which register it writes to, and where it came from in the source program,
+/// so errors can point at the original instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CompiledInstruction {
+    op: Op,
+    target: usize,
+    pc: usize,
+}
+
+/// An ALU program compiled into a compact bytecode which is much cheaper to run than the original instructions.
+///
+/// Compilation folds constants (tracking which registers have known values, e.g., right after a 'mul x 0'), drops the
+/// no-ops this leaves behind (like 'add x 0' or 'mul x 1'), and removes dead stores, i.e., writes to registers which
+/// are overwritten before anyone reads them. The result works from any initial register state, just like the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledProgram {
+    code: Vec<CompiledInstruction>,
+}
+
+impl CompiledProgram {
+    /// Panics on instructions the parser would reject, like writes to a literal.
+    pub fn new(instructions: &[ALUInstruction]) -> Self {
+        let mut code = CompiledProgram::fold_constants(instructions);
+        CompiledProgram::eliminate_dead_stores(&mut code);
+        CompiledProgram { code }
+    }
+
+    /// Number of instructions left after compilation.
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+
+    fn fold_constants(instructions: &[ALUInstruction]) -> Vec<CompiledInstruction> {
+        // The known value of every register, if any. Nothing is known about the initial state.
+        let mut known: [Option<i64>; 4] = [None; 4];
+        let mut code = Vec::with_capacity(instructions.len());
+
+        for (pc, instruction) in instructions.iter().enumerate() {
+            let target = Alu::target(instruction)
+                .unwrap_or_else(|| panic!("Invalid instruction format: {:?}", instruction));
+            let src = match instruction {
+                ALUInstruction::INP(_) => None,
+                ALUInstruction::ADD(_, src)
+                | ALUInstruction::MUL(_, src)
+                | ALUInstruction::DIV(_, src)
+                | ALUInstruction::MOD(_, src)
+                | ALUInstruction::EQL(_, src) => Some(match src {
+                    Expr::LITERAL(val) => Operand::Imm(*val),
+                    Expr::REG(name) => match known[reg_idx(*name)] {
+                        Some(val) => Operand::Imm(val),
+                        None => Operand::Reg(reg_idx(*name)),
+                    },
+                }),
+            };
+            let a = known[target];
+
+            // Work out the result if it is known at compile time, or else the op which computes it at run time.
+            let op = match (instruction, src) {
+                (ALUInstruction::INP(_), _) => Op::Inp,
+                (ALUInstruction::ADD(..), Some(src)) => match (a, src) {
+                    (Some(a), Operand::Imm(b)) => Op::Set(Operand::Imm(a + b)),
+                    (Some(0), src) => Op::Set(src),
+                    (_, Operand::Imm(0)) => continue,
+                    (_, src) => Op::Add(src),
+                },
+                (ALUInstruction::MUL(..), Some(src)) => match (a, src) {
+                    (Some(a), Operand::Imm(b)) => Op::Set(Operand::Imm(a * b)),
+                    (Some(0), _) | (_, Operand::Imm(0)) => Op::Set(Operand::Imm(0)),
+                    (Some(1), src) => Op::Set(src),
+                    (_, Operand::Imm(1)) => continue,
+                    (_, src) => Op::Mul(src),
+                },
+                (ALUInstruction::DIV(..), Some(src)) => match (a, src) {
+                    (Some(a), Operand::Imm(b)) if b != 0 => Op::Set(Operand::Imm(a / b)),
+                    (_, Operand::Imm(1)) => continue,
+                    (_, src) => Op::Div(src),
+                },
+                (ALUInstruction::MOD(..), Some(src)) => match (a, src) {
+                    (Some(a), Operand::Imm(b)) if a >= 0 && b > 0 => Op::Set(Operand::Imm(a % b)),
+                    (_, src) => Op::Mod(src),
+                },
+                (ALUInstruction::EQL(..), Some(src)) => match (a, src) {
+                    (Some(a), Operand::Imm(b)) => Op::Set(Operand::Imm(i64::from(a == b))),
+                    (_, Operand::Reg(reg)) if reg == target => Op::Set(Operand::Imm(1)),
+                    (_, src) => Op::Eql(src),
+                },
+                (_, None) => unreachable!("Only 'inp' has no source operand."),
+            };
+
+            known[target] = match op {
+                Op::Set(Operand::Imm(val)) => Some(val),
+                Op::Set(Operand::Reg(reg)) => known[reg],
+                _ => None,
+            };
+            code.push(CompiledInstruction { op, target, pc });
+        }
+
+        code
+    }
+
+    /// Removes the instructions whose results are always overwritten before being read. All registers are considered
+    /// read at the end, since the caller may look at any of them.
+    fn eliminate_dead_stores(code: &mut Vec<CompiledInstruction>) {
+        let mut live = [true; 4];
+        let mut keep = vec![true; code.len()];
+        for (idx, instruction) in code.iter().enumerate().rev() {
+            if !live[instruction.target] && !instruction.op.has_side_effects() {
+                keep[idx] = false;
+                continue;
+            }
+            live[instruction.target] = instruction.op.reads_target();
+            if let Some(Operand::Reg(reg)) = instruction.op.source() {
+                live[reg] = true;
+            }
+        }
+
+        let mut keep_iter = keep.iter();
+        code.retain(|_| *keep_iter.next().unwrap());
+    }
+
+    /// Runs the program starting from the given registers, and returns the final ones.
+    pub fn run(&self, mut registers: [i64; 4], input: &[i64]) -> Result<[i64; 4], AluError> {
+        let mut input = input.iter();
+        for &CompiledInstruction { op, target, pc } in &self.code {
+            let value = |operand: Operand| match operand {
+                Operand::Reg(reg) => registers[reg],
+                Operand::Imm(val) => val,
+            };
+            let a = registers[target];
+            registers[target] = match op {
+                Op::Inp => *input.next().ok_or(AluError::OutOfInput { pc })?,
+                Op::Set(src) => value(src),
+                Op::Add(src) => a + value(src),
+                Op::Mul(src) => a * value(src),
+                Op::Div(src) => match value(src) {
+                    0 => return Err(AluError::DivByZero { pc }),
+                    b => a / b,
+                },
+                Op::Mod(src) => match value(src) {
+                    b if b <= 0 => return Err(AluError::InvalidModulus { pc }),
+                    _ if a < 0 => return Err(AluError::NegativeMod { pc }),
+                    b => a % b,
+                },
+                Op::Eql(src) => i64::from(a == value(src)),
+            };
+        }
+        Ok(registers)
+    }
+}
+
+impl Program {
+    pub fn compile(&self) -> CompiledProgram {
+        CompiledProgram::new(&self.instructions)
+    }
+}
+
+fn vec_to_num(digits: &[i64]) -> i64 {
+    let mut nr = 0;
+    for d in digits {
+        nr = nr * 10 + d;
+    }
+    nr
+}
+
+#[cfg(test)]
+fn get_program_spec() -> Vec<(i64, i64, i64)> {
+    // 1  = peek
+    // 26 = pop
+    vec![
+        (1, 14, 12), // d00
+        (1, 10, 9),
+        (1, 13, 8),
+        (26, -8, 3),
+        (1, 11, 0),
+        (1, 11, 11),
+        (1, 14, 10),
+        (26, -11, 13),
+        (1, 14, 3),
+        (26, -1, 10),
+        (26, -8, 10), // d10
+        (26, -5, 14), // d11
+        (26, -16, 6), // d12
+        (26, -6, 5),  // d13
+    ]
+}
+
+/// The shape of the program's block for each digit. The '?'s stand for the constants which differ between blocks.
+const BLOCK_TEMPLATE: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ?", "add x ?", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ?",
+    "mul y x", "add z y",
+];
+
+/// The constants of one digit's block. With 'x = z % 26 + check', the block divides 'z' by 'div', and then pushes
+/// 'digit + offset' (i.e., 'z = z * 26 + digit + offset') unless 'x == digit'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitBlock {
+    /// Either 1 (peek at the top of the stack) or 26 (pop it).
+    pub div: i64,
+    /// Added to the top of the stack before comparing it with the digit.
+    pub check: i64,
+    /// Added to the digit before pushing it, if the comparison fails.
+    pub offset: i64,
+}
+
+/// Requires that 'digits[later] == digits[earlier] + diff'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DigitConstraint {
+    pub earlier: usize,
+    pub later: usize,
+    pub diff: i64,
+}
+
+/// Matches the program against 'BLOCK_TEMPLATE', block by block, and returns the constants of every block.
+pub fn extract_blocks(instructions: &[ALUInstruction]) -> Result<Vec<DigitBlock>, String> {
+    if !instructions.len().is_multiple_of(BLOCK_TEMPLATE.len()) {
+        return Err(format!(
+            "Expected a multiple of {} instructions, got {}",
+            BLOCK_TEMPLATE.len(),
+            instructions.len()
+        ));
+    }
+
+    let mut blocks = Vec::new();
+    for (block_idx, block) in instructions.chunks(BLOCK_TEMPLATE.len()).enumerate() {
+        let mut constants = Vec::new();
+        for (offset, (instruction, pattern)) in block.iter().zip(BLOCK_TEMPLATE).enumerate() {
+            let text = instruction.to_string();
+            let mismatch = || {
+                format!(
+                    "Instruction {} ('{}') does not match the block template ('{}')",
+                    block_idx * BLOCK_TEMPLATE.len() + offset,
+                    text,
+                    pattern
+                )
+            };
+            let (tokens, pattern_tokens): (Vec<&str>, Vec<&str>) =
+                (text.split(' ').collect(), pattern.split(' ').collect());
+            if tokens.len() != pattern_tokens.len() {
+                return Err(mismatch());
+            }
+            for (token, pattern_token) in tokens.iter().zip(&pattern_tokens) {
+                if *pattern_token == "?" {
+                    constants.push(token.parse::<i64>().map_err(|_| mismatch())?);
+                } else if token != pattern_token {
+                    return Err(mismatch());
+                }
+            }
+        }
+        blocks.push(DigitBlock {
+            div: constants[0],
+            check: constants[1],
+            offset: constants[2],
+        });
+    }
+    Ok(blocks)
+}
+
+/// Pairs up every pushing block with the block which pops its value, and returns the constraints the digits must meet
+/// for the stack to end up empty, i.e., for 'z' to end up zero.
+pub fn derive_constraints(blocks: &[DigitBlock]) -> Result<Vec<DigitConstraint>, String> {
+    let mut stack: Vec<(usize, &DigitBlock)> = Vec::new();
+    let mut constraints = Vec::new();
+
+    for (idx, block) in blocks.iter().enumerate() {
+        match block.div {
+            1 => {
+                // The top of the stack is in [0, 26), so with a check of 10 or more it can never equal a digit, and
+                // the digit always gets pushed.
+                if block.check < 10 {
+                    return Err(format!(
+                        "Block {} peeks, but may not push (check = {})",
+                        idx, block.check
+                    ));
+                }
+                stack.push((idx, block));
+            }
+            26 => {
+                let (earlier, pushed) = stack
+                    .pop()
+                    .ok_or(format!("Block {} pops from an empty stack", idx))?;
+                // The pushed value is 'digits[earlier] + pushed.offset', and we must not push again, so the check
+                // needs to succeed.
+                constraints.push(DigitConstraint {
+                    earlier,
+                    later: idx,
+                    diff: pushed.offset + block.check,
+                });
+            }
+            other => return Err(format!("Block {} divides z by {}", idx, other)),
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err(format!("{} pushed values are never popped", stack.len()));
+    }
+    Ok(constraints)
+}
+
+/// Returns the (largest, smallest) model numbers meeting all the constraints, or 'None' if there are none.
+pub fn model_number_range(n_digits: usize, constraints: &[DigitConstraint]) -> Option<(i64, i64)> {
+    let mut digits_max = vec![0i64; n_digits];
+    let mut digits_min = vec![0i64; n_digits];
+    for constraint in constraints {
+        if constraint.diff.abs() > 8 {
+            return None;
+        }
+        // Make the first digit of the pair as large (or small) as possible, since it is the more significant one.
+        digits_max[constraint.earlier] = 9.min(9 - constraint.diff);
+        digits_min[constraint.earlier] = 1.max(1 - constraint.diff);
+        digits_max[constraint.later] = digits_max[constraint.earlier] + constraint.diff;
+        digits_min[constraint.later] = digits_min[constraint.earlier] + constraint.diff;
+    }
+
+    Some((vec_to_num(&digits_max), vec_to_num(&digits_min)))
+}
+
+/// This version relies on the hint that the instructions implement a simple stack.
+///
+/// The stack is represented as the digits of a base-26 number stored in 'z'. The insight is you want the stack to be
+/// empty at the conclusion of the program, which means you need the digits to satisfy certain conditions so as to
+/// prevent any PUSH operations onto the stack which can be presented.
+///
+/// 'z = z * 26 + foo' pushes 'foo' onto the stack.
+/// 'x = z % 26; z /= 1' peeks at the top of the stack, putting the value into x.
+/// 'x = z % 26; z /= 26' pops x off z.
+///
+/// I found this final hint in the AoC Day 24 reddit thread, but did not actually look at any solution code /
+/// spreadsheet.
+///
+/// I first derived the constraints by hand for my input (e.g., d00 == d13 - 6 and d01 == d12 + 7), but they now get
+/// extracted from the program itself, so this works for anyone's input.
+fn solve_version_c(instructions: &[ALUInstruction]) -> (Option<i64>, Option<i64>) {
+    let blocks = extract_blocks(instructions).unwrap_or_else(|err| panic!("{}", err));
+    let constraints = derive_constraints(&blocks).unwrap_or_else(|err| panic!("{}", err));
+    match model_number_range(blocks.len(), &constraints) {
+        Some((max, min)) => (Some(max), Some(min)),
+        None => (None, None),
+    }
+}
+
+/// Maps each output 'z' value of a partial program to the (largest, smallest) input number that produced it.
+type ZStates = HashMap<i64, (i64, i64)>;
+
+/// How many 'z' states a worker processes before reporting progress. The progress bar takes a lock on every update.
+const PROGRESS_BATCH: usize = 4096;
+
+/// Records that the given inputs lead to 'z', keeping the largest and smallest of them.
+fn merge_state(states: &mut ZStates, z: i64, (max_input, min_input): (i64, i64)) {
+    states
+        .entry(z)
+        .and_modify(|(best_max, best_min)| {
+            *best_max = (*best_max).max(max_input);
+            *best_min = (*best_min).min(min_input);
+        })
+        .or_insert((max_input, min_input));
+}
+
+/// Runs one digit's block on every state in 'chunk' and every possible digit, dropping the 'z' values above 'z_bound'.
+fn expand_states(
+    block: &CompiledProgram,
+    chunk: &[(i64, (i64, i64))],
+    z_bound: i64,
+    progress: &ProgressBar,
+) -> Result<ZStates, AluError> {
+    let mut new_states = ZStates::new();
+    for batch in chunk.chunks(PROGRESS_BATCH) {
+        for &(initial_z, (max_input, min_input)) in batch {
+            for digit in DIGIT_RANGE.lo..=DIGIT_RANGE.hi {
+                let z_val = block.run([0, 0, 0, initial_z], &[digit])?[3];
+                // Limits the search space when we know we can't possibly 'div' z enough to reach zero by the end.
+                if z_val > z_bound {
+                    continue;
+                }
+                merge_state(
+                    &mut new_states,
+                    z_val,
+                    (max_input * 10 + digit, min_input * 10 + digit),
+                );
+            }
+        }
+        progress.inc(batch.len() as u64);
+    }
+    Ok(new_states)
+}
+
+/// The first attempt to solve the problem - using per-module caching.
+///
+/// Used to take up to 15 min on a 2018 i9. Compiling the program, tracking the max and min inputs in a single pass, and
+/// splitting every digit's 'z' states across 'n_threads' workers all help, but pruning with 'max_viable_z' is what
+/// really brings it down to well under a second. Use 'aoc21 alu-search' to run it with a progress bar.
+pub fn solve_version_a(
+    commands: &[ALUInstruction],
+    n_threads: usize,
+    show_progress: bool,
+) -> Result<(Option<i64>, Option<i64>), String> {
+    assert!(n_threads > 0, "Need at least one search thread.");
+    let mut z_states = ZStates::new();
+    z_states.insert(0, (0, 0));
+    // Past these, there is no way to bring z back down to zero by the end. This used to be a hand-tuned z > 26^4 check
+    // for the last few digits only.
+    let max_viable_z = max_viable_z(commands);
+    // Interpreting the instructions one by one used to be the bottleneck.
+    let (block_starts, blocks): (Vec<usize>, Vec<CompiledProgram>) = split_digit_blocks(commands)
+        .into_iter()
+        .map(|(start, block)| (start, CompiledProgram::new(block)))
+        .unzip();
+    if blocks.is_empty() {
+        return Err("The program does not read any digits.".to_string());
+    }
+    if blocks.len() != max_viable_z.len() {
+        return Err(format!(
+            "Split the program into {} digit blocks, but found bounds for {} digits.",
+            blocks.len(),
+            max_viable_z.len()
+        ));
+    }
+
+    for (digit_idx, (block, &z_bound)) in blocks.iter().zip(&max_viable_z).enumerate() {
+        let progress = if show_progress {
+            ProgressBar::new(z_states.len() as u64)
+        } else {
+            ProgressBar::hidden()
+        };
+        progress.set_style(
+            ProgressStyle::default_bar()
+                .template("Digit {msg} [{elapsed_precise}] {bar:40} {pos}/{len} z's ({eta})"),
+        );
+        progress.set_message(format!("{:2} / {}", digit_idx + 1, blocks.len()));
+
+        // Every worker builds its own map from a slice of the states, and the maps are then merged, which is much
+        // cheaper than sharing a single map between the threads.
+        let states: Vec<(i64, (i64, i64))> = z_states.into_iter().collect();
+        let chunk_size = states.len().div_ceil(n_threads).max(1);
+        let partial_states: Result<Vec<ZStates>, AluError> = thread::scope(|scope| {
+            let workers: Vec<_> = states
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(|| expand_states(block, chunk, z_bound, &progress)))
+                .collect();
+            workers
+                .into_iter()
+                .map(|worker| worker.join().expect("A search worker panicked."))
+                .collect()
+        });
+        // The block's instructions are numbered from its own start.
+        let partial_states = partial_states.map_err(|err| {
+            format!(
+                "The ALU crashed on digit {} (whose block starts at instruction {}): {}",
+                digit_idx + 1,
+                block_starts[digit_idx],
+                err
+            )
+        })?;
+
+        z_states = ZStates::new();
+        for partial in partial_states {
+            for (z_val, inputs) in partial {
+                merge_state(&mut z_states, z_val, inputs);
+            }
+        }
+        progress.finish_and_clear();
+        if show_progress {
+            println!(
+                "Digit {:2} / {}: {} z's remain.",
+                digit_idx + 1,
+                blocks.len(),
+                z_states.len()
+            );
+        }
+    }
+
+    Ok(match z_states.get(&0) {
+        Some(&(max_input, min_input)) => (Some(max_input), Some(min_input)),
+        None => (None, None),
+    })
+}
+
+pub fn parse(input: &str) -> Program {
+    input
+        .parse()
+        .unwrap_or_else(|err| panic!("Invalid ALU program: {}", err))
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(program: &Program) -> (Answer, Answer) {
+    let (part_one_opt, part_two_opt) = solve_version_c(&program.instructions);
+
+    // The answers computed with the slow method are recorded in 'answers.toml', and 'aoc21 verify' (as well as the
+    // integration tests) validate the faster re-implementations against them.
+    (
+        part_one_opt.expect("Could not find a solution!!!").into(),
+        part_two_opt.expect("Could not find a solution!!!").into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, RngCore, SeedableRng};
+
+    use super::*;
+
+    /// The digits of 'number', most significant first, like the ALU reads them.
+    fn model_digits(number: i64) -> Vec<i64> {
+        number
+            .to_string()
+            .chars()
+            .map(|ch| ch.to_digit(10).unwrap() as i64)
+            .collect()
+    }
+
+    fn load(name: &str) -> Program {
+        let path = format!("{}/input/{}", env!("CARGO_MANIFEST_DIR"), name);
+        parse(&std::fs::read_to_string(path).unwrap())
+    }
+
+    #[test]
+    fn test_parse_commented() {
+        let program = load("24.txt");
+        assert_eq!(program.instructions.len(), 14 * 18);
+        assert_eq!(load("24-commented.txt"), program);
+
+        assert!("inp 3".parse::<Program>().is_err());
+        assert!("add x".parse::<Program>().is_err());
+        assert!("mul q 2".parse::<Program>().is_err());
+        assert!("jmp x 2".parse::<Program>().is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let run = |code: &str, input: &[i64]| {
+            let program: Program = code.parse().unwrap();
+            Alu::new().run(&program.instructions, input)
+        };
+        assert_eq!(
+            run("inp x\ndiv y x", &[0]),
+            Err(AluError::DivByZero { pc: 1 })
+        );
+        assert_eq!(run("mod x 0", &[]), Err(AluError::InvalidModulus { pc: 0 }));
+        assert_eq!(
+            run("mod x -3", &[]),
+            Err(AluError::InvalidModulus { pc: 0 })
+        );
+        assert_eq!(
+            run("add x -5\nmod x 3", &[]),
+            Err(AluError::NegativeMod { pc: 1 })
+        );
+        assert_eq!(
+            run("inp w\ninp x", &[1]),
+            Err(AluError::OutOfInput { pc: 1 })
+        );
+        // Division by a negative number is fine, and truncates towards zero.
+        assert_eq!(run("add x 7\ndiv x -2", &[]), Ok(()));
+
+        // The parser rejects these, but hand-built instructions can still contain them.
+        let literal_target = ALUInstruction::ADD(Expr::LITERAL(3), Expr::LITERAL(1));
+        let bad_target = ALUInstruction::INP(Expr::REG('q'));
+        let bad_source = ALUInstruction::MUL(Expr::REG('x'), Expr::REG('q'));
+        for instruction in [literal_target, bad_target, bad_source] {
+            let mut alu = Alu::new();
+            alu.registers = [1, 2, 3, 4];
+            assert_eq!(
+                alu.run(
+                    &[
+                        ALUInstruction::ADD(Expr::REG('z'), Expr::LITERAL(1)),
+                        instruction
+                    ],
+                    &[7]
+                ),
+                Err(AluError::InvalidOperand { pc: 1, instruction })
+            );
+            assert_eq!(alu.registers, [1, 2, 3, 5]);
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        // The binary conversion example from the puzzle.
+        let program: Program =
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\n\
+                                div w 2\nmod w 2"
+                .parse()
+                .unwrap();
+        let mut alu = Alu::new();
+        let trace = alu.trace(&program.instructions, &[11]).unwrap();
+        assert_eq!(trace.len(), program.instructions.len());
+        assert_eq!(trace[0], [11, 0, 0, 0]);
+        assert_eq!(alu.registers, [1, 0, 1, 1]);
+        assert_eq!(alu.pc, program.instructions.len());
+    }
+
+    #[test]
+    fn test_extract_blocks() {
+        let blocks = extract_blocks(&load("24.txt").instructions).unwrap();
+        // The constants I originally reverse-engineered by hand.
+        let by_hand: Vec<DigitBlock> = get_program_spec()
+            .into_iter()
+            .map(|(div, check, offset)| DigitBlock { div, check, offset })
+            .collect();
+        assert_eq!(blocks, by_hand);
+
+        let constraints = derive_constraints(&blocks).unwrap();
+        assert_eq!(constraints.len(), 7);
+        assert!(constraints.contains(&DigitConstraint {
+            earlier: 0,
+            later: 13,
+            diff: 6
+        }));
+        assert!(constraints.contains(&DigitConstraint {
+            earlier: 1,
+            later: 12,
+            diff: -7
+        }));
+
+        let mut broken = load("24.txt").instructions;
+        broken[4] = ALUInstruction::DIV(Expr::REG('z'), Expr::REG('w'));
+        assert!(extract_blocks(&broken).is_err());
+    }
+
+    #[test]
+    fn test_model_number_range() {
+        let constraints = vec![
+            DigitConstraint {
+                earlier: 0,
+                later: 3,
+                diff: 2,
+            },
+            DigitConstraint {
+                earlier: 1,
+                later: 2,
+                diff: -8,
+            },
+        ];
+        assert_eq!(model_number_range(4, &constraints), Some((7919, 1913)));
+        let impossible = vec![DigitConstraint {
+            earlier: 0,
+            later: 1,
+            diff: 9,
+        }];
+        assert_eq!(model_number_range(2, &impossible), None);
+    }
+
+    #[test]
+    fn test_interval_ops() {
+        let a = Interval::new(-3, 5);
+        assert_eq!(a.add(&Interval::constant(2)), Interval::new(-1, 7));
+        assert_eq!(a.mul(&Interval::new(-2, 1)), Interval::new(-10, 6));
+        assert_eq!(a.div(&Interval::constant(2)), Some(Interval::new(-1, 2)));
+        assert_eq!(a.div(&Interval::new(-1, 1)), Some(Interval::new(-5, 5)));
+        assert_eq!(a.div(&Interval::constant(0)), None);
+        assert_eq!(
+            Interval::new(27, 30).modulo(&Interval::constant(26)),
+            Some(Interval::new(1, 4))
+        );
+        assert_eq!(
+            Interval::new(20, 30).modulo(&Interval::constant(26)),
+            Some(Interval::new(0, 25))
+        );
+        assert_eq!(Interval::new(-5, -1).modulo(&Interval::constant(26)), None);
+        assert_eq!(
+            Interval::new(10, 35).eql(&DIGIT_RANGE),
+            Interval::constant(0)
+        );
+        assert_eq!(Interval::new(5, 35).eql(&DIGIT_RANGE), Interval::new(0, 1));
+    }
+
+    #[test]
+    fn test_analysis() {
+        let program = load("24.txt");
+        let analysis = analyze(&program.instructions, [Interval::constant(0); 4]);
+        assert_eq!(analysis.z_at_input.len(), 14);
+        assert_eq!(analysis.z_at_input[0], Interval::constant(0));
+        // Each of the 7 pushing blocks compares the digit to something at least 10, so both of their 'eql's are
+        // known in advance.
+        assert!(analysis.constant_eqls.len() >= 14);
+        assert!(analysis.constant_eqls.contains(&(6, 0)));
+        assert!(analysis.constant_eqls.contains(&(7, 1)));
+        assert!(analysis.registers.unwrap()[3].contains(0));
+
+        // Nothing reaches zero from a 'z' too large to be popped off in time.
+        let last_block = &program.instructions[13 * 18..];
+        let zero = Interval::constant(0);
+        assert!(may_reach_zero(
+            last_block,
+            [zero, zero, zero, Interval::new(0, 25)]
+        ));
+        assert!(!may_reach_zero(
+            last_block,
+            [zero, zero, zero, Interval::new(26, 1000)]
+        ));
+        // The block overwrites 'w' with the digit and clears 'x' and 'y' before reading them, so their incoming
+        // values do not matter.
+        let any = Interval::new(-1000, 1000);
+        assert!(may_reach_zero(
+            last_block,
+            [any, any, any, Interval::new(0, 25)]
+        ));
+        assert!(!may_reach_zero(
+            last_block,
+            [any, any, any, Interval::new(26, 1000)]
+        ));
+    }
+
+    #[test]
+    fn test_parallel_search() {
+        let program = load("24.txt");
+        let expected = solve_version_c(&program.instructions);
+        assert!(expected.0.is_some());
+        // More threads than some digits have 'z' states, to make sure empty and tiny chunks work too.
+        for n_threads in [1, 3, 16] {
+            assert_eq!(
+                solve_version_a(&program.instructions, n_threads, false),
+                Ok(expected)
+            );
+        }
+
+        // Blocks are split on 'inp', so they need not have the same length, and may start with some setup code.
+        let search = |code: &str| solve_version_a(&parse(code).instructions, 2, false);
+        assert_eq!(
+            search("add z 3\ninp w\nadd z w\nmod z 12"),
+            Ok((Some(9), Some(9)))
+        );
+        assert_eq!(
+            search("inp w\nadd z w\ninp x\nmul x -1\nadd z x"),
+            Ok((Some(99), Some(11)))
+        );
+        assert!(search("add z 1").is_err());
+        // The second digit's block divides by zero when the digit is 5.
+        let err = search("inp w\ninp x\nadd x -5\ndiv z x").unwrap_err();
+        assert!(
+            err.contains("digit 2 (whose block starts at instruction 1)"),
+            "{}",
+            err
+        );
+        assert!(
+            err.ends_with("Division by zero at instruction 2"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_max_viable_z() {
+        let program = load("24.txt");
+        let bounds = max_viable_z(&program.instructions);
+        assert_eq!(bounds.len(), 14);
+        assert_eq!(bounds[13], 0);
+        assert_eq!(bounds[12], 25);
+
+        // The bounds must never cut off an actual solution.
+        for model_number in [39999698799429i64, 18116121134117] {
+            let trace = Alu::new()
+                .trace(&program.instructions, &model_digits(model_number))
+                .unwrap();
+            for digit_idx in 0..14 {
+                let z_after_block = trace[(digit_idx + 1) * 18 - 1][3];
+                assert!(z_after_block <= bounds[digit_idx]);
+            }
+        }
+    }
+
+    /// Makes a random (but valid) program out of all kinds of instructions, with small literals so that constants are
+    /// folded and registers are reused a lot.
+    fn random_program(rng: &mut impl Rng, len: usize) -> Vec<ALUInstruction> {
+        let reg = |rng: &mut dyn RngCore| Expr::REG(REGISTER_NAMES[rng.gen_range(0..4)]);
+        (0..len)
+            .map(|_| {
+                let target = reg(rng);
+                let src = if rng.gen_bool(0.5) {
+                    reg(rng)
+                } else {
+                    Expr::LITERAL(rng.gen_range(-3..=30))
+                };
+                match rng.gen_range(0..6) {
+                    0 => ALUInstruction::INP(target),
+                    1 => ALUInstruction::ADD(target, src),
+                    2 => ALUInstruction::MUL(target, src),
+                    3 => ALUInstruction::DIV(target, src),
+                    4 => ALUInstruction::MOD(target, src),
+                    _ => ALUInstruction::EQL(target, src),
+                }
+            })
+            .collect()
+    }
+
+    fn interpret(
+        instructions: &[ALUInstruction],
+        registers: [i64; 4],
+        input: &[i64],
+    ) -> Result<[i64; 4], AluError> {
+        let mut alu = Alu::new();
+        alu.registers = registers;
+        alu.run(instructions, input).map(|_| alu.registers)
+    }
+
+    #[test]
+    fn test_compile_shrinks_blocks() {
+        let program = load("24.txt");
+        let compiled = program.compile();
+        // Each 'mul x 0' / 'mul y 0' gets folded into the next instruction, for starters.
+        assert!(compiled.len() < program.instructions.len() - 2 * 14);
+        assert!(!compiled.is_empty());
+    }
+
+    #[test]
+    fn test_compiled_matches_interpreter() {
+        let mut rng = StdRng::seed_from_u64(24);
+
+        let program = load("24.txt");
+        let compiled = program.compile();
+        for _ in 0..2000 {
+            let digits: Vec<i64> = (0..14).map(|_| rng.gen_range(1..=9)).collect();
+            assert_eq!(
+                compiled.run([0; 4], &digits),
+                interpret(&program.instructions, [0; 4], &digits)
+            );
+        }
+        // Blocks on their own, starting from arbitrary registers.
+        for block in program.instructions.chunks(18) {
+            let compiled_block = CompiledProgram::new(block);
+            for _ in 0..200 {
+                let registers = [0; 4].map(|_: i64| rng.gen_range(0..100_000));
+                let digit = [rng.gen_range(1..=9)];
+                assert_eq!(
+                    compiled_block.run(registers, &digit),
+                    interpret(block, registers, &digit)
+                );
+            }
+        }
+
+        // Random programs, including ones which crash, must crash at the same instruction.
+        for _ in 0..5000 {
+            let len = rng.gen_range(1..30);
+            let instructions = random_program(&mut rng, len);
+            let compiled = CompiledProgram::new(&instructions);
+            let registers = [0; 4].map(|_: i64| rng.gen_range(-50..50));
+            let input: Vec<i64> = (0..rng.gen_range(0..5))
+                .map(|_| rng.gen_range(-9..=9))
+                .collect();
+            assert_eq!(
+                compiled.run(registers, &input),
+                interpret(&instructions, registers, &input),
+                "Mismatch on {:?}",
+                instructions
+            );
+        }
+    }
+
+    #[test]
+    fn test_model_numbers() {
+        let program = load("24.txt");
+        for model_number in [39999698799429i64, 18116121134117] {
+            let mut alu = Alu::new();
+            alu.run(&program.instructions, &model_digits(model_number))
+                .unwrap();
+            assert_eq!(alu.reg('z'), 0);
+        }
+        let mut alu = Alu::new();
+        alu.run(&program.instructions, &model_digits(39999698799428))
+            .unwrap();
+        assert_ne!(alu.reg('z'), 0);
+    }
+}
@@ -0,0 +1,374 @@
+//! 2021 AoC Day 25: Sea Cucumber
+//!
+//! Herds of sea cucumbers move east, then south, on a torus, until they get stuck.
+//!
+//! The herds are bitboards: every cucumber only cares whether the next cell in its direction of travel is free, so
+//! storing the east-facing herd by rows and the south-facing one by columns turns a whole herd's move into a few shifts
+//! and masks of u64 words. Each herd also keeps a copy in the other orientation, to tell which cells the other herd has
+//! to stay out of, and only a couple of bits of that copy change for every cucumber which moves.
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::answer::Answer;
+use crate::grid::Grid;
+
+const EAST: char = '>';
+const SOUTH: char = 'v';
+const EMPTY: char = '.';
+
+/// Gives up on herds which never stop, which can happen, e.g., when a row has a single free cell for its herd to chase.
+const MAX_STEPS: usize = 100000;
+
+const WORD_BITS: usize = 64;
+
+/// Lines of bits, e.g., the rows or the columns of a grid, packed into u64 words with the lowest bit first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BitLines {
+    line_len: usize,
+    words_per_line: usize,
+    words: Vec<u64>,
+}
+
+impl BitLines {
+    fn new(n_lines: usize, line_len: usize) -> Self {
+        let words_per_line = line_len.div_ceil(WORD_BITS);
+        BitLines {
+            line_len,
+            words_per_line,
+            words: vec![0; n_lines * words_per_line],
+        }
+    }
+
+    fn line(&self, idx: usize) -> &[u64] {
+        &self.words[idx * self.words_per_line..(idx + 1) * self.words_per_line]
+    }
+
+    fn line_mut(&mut self, idx: usize) -> &mut [u64] {
+        &mut self.words[idx * self.words_per_line..(idx + 1) * self.words_per_line]
+    }
+
+    fn get(&self, line: usize, bit: usize) -> bool {
+        (self.line(line)[bit / WORD_BITS] >> (bit % WORD_BITS)) & 1 == 1
+    }
+
+    fn set(&mut self, line: usize, bit: usize) {
+        self.line_mut(line)[bit / WORD_BITS] |= 1 << (bit % WORD_BITS);
+    }
+
+    fn clear(&mut self, line: usize, bit: usize) {
+        self.line_mut(line)[bit / WORD_BITS] &= !(1 << (bit % WORD_BITS));
+    }
+
+    /// The valid bits of the last word of every line. The ones past the end of the line are always zero.
+    fn last_word_mask(&self) -> u64 {
+        match self.line_len % WORD_BITS {
+            0 => u64::MAX,
+            n_bits => (1 << n_bits) - 1,
+        }
+    }
+}
+
+/// Moves bit i of 'line' to bit i + 1, and the last bit around to the first one.
+fn rotate_up(line: &[u64], line_len: usize, mask: u64, out: &mut [u64]) {
+    let last_bit = line_len - 1;
+    let mut carry = (line[last_bit / WORD_BITS] >> (last_bit % WORD_BITS)) & 1;
+    for (out_word, &word) in out.iter_mut().zip(line) {
+        *out_word = (word << 1) | carry;
+        carry = word >> (WORD_BITS - 1);
+    }
+    *out.last_mut().unwrap() &= mask;
+}
+
+/// Moves bit i + 1 of 'line' to bit i, and the first bit around to the last one.
+fn rotate_down(line: &[u64], line_len: usize, out: &mut [u64]) {
+    let first = line[0] & 1;
+    for word_idx in 0..line.len() {
+        let next_word = line.get(word_idx + 1).copied().unwrap_or(0);
+        out[word_idx] = (line[word_idx] >> 1) | ((next_word & 1) << (WORD_BITS - 1));
+    }
+    let last_bit = line_len - 1;
+    out[last_bit / WORD_BITS] |= first << (last_bit % WORD_BITS);
+}
+
+/// Moves every cucumber of 'herd' whose next cell is free of both herds, and returns how many moved. 'herd' and 'other'
+/// have one line per row (or column) in the herd's direction of travel, and 'mirror' is 'herd' the other way around.
+fn move_herd(herd: &mut BitLines, other: &BitLines, mirror: &mut BitLines) -> usize {
+    let (line_len, mask) = (herd.line_len, herd.last_word_mask());
+    let n_words = herd.words_per_line;
+    let mut free = vec![0u64; n_words];
+    let mut free_ahead = vec![0u64; n_words];
+    let mut moved = vec![0u64; n_words];
+    let mut arrived = vec![0u64; n_words];
+    let mut n_moved = 0;
+
+    for line_idx in 0..mirror.line_len {
+        let (line, other_line) = (herd.line(line_idx), other.line(line_idx));
+        for word_idx in 0..n_words {
+            free[word_idx] = !(line[word_idx] | other_line[word_idx]);
+        }
+        free[n_words - 1] &= mask;
+        rotate_down(&free, line_len, &mut free_ahead);
+
+        let mut any_moved = false;
+        for word_idx in 0..n_words {
+            moved[word_idx] = line[word_idx] & free_ahead[word_idx];
+            any_moved |= moved[word_idx] != 0;
+        }
+        if !any_moved {
+            continue;
+        }
+        rotate_up(&moved, line_len, mask, &mut arrived);
+
+        for (word_idx, &moved_word) in moved.iter().enumerate() {
+            let mut bits = moved_word;
+            while bits != 0 {
+                let bit = word_idx * WORD_BITS + bits.trailing_zeros() as usize;
+                mirror.clear(bit, line_idx);
+                mirror.set((bit + 1) % line_len, line_idx);
+                n_moved += 1;
+                bits &= bits - 1;
+            }
+        }
+        let line = herd.line_mut(line_idx);
+        for word_idx in 0..n_words {
+            line[word_idx] = (line[word_idx] & !moved[word_idx]) | arrived[word_idx];
+        }
+    }
+
+    n_moved
+}
+
+/// Both herds as bitboards, stored both by rows and by columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Herds {
+    east_rows: BitLines,
+    east_cols: BitLines,
+    south_rows: BitLines,
+    south_cols: BitLines,
+}
+
+impl Herds {
+    pub fn from_grid(cucumbers: &Grid<char>) -> Self {
+        let (rows, cols) = (cucumbers.rows(), cucumbers.cols());
+        let mut herds = Herds {
+            east_rows: BitLines::new(rows, cols),
+            east_cols: BitLines::new(cols, rows),
+            south_rows: BitLines::new(rows, cols),
+            south_cols: BitLines::new(cols, rows),
+        };
+        for (row, col) in cucumbers.positions() {
+            match cucumbers[(row, col)] {
+                EAST => {
+                    herds.east_rows.set(row, col);
+                    herds.east_cols.set(col, row);
+                }
+                SOUTH => {
+                    herds.south_rows.set(row, col);
+                    herds.south_cols.set(col, row);
+                }
+                EMPTY => {}
+                other => panic!("Invalid cell: {:?}", other),
+            }
+        }
+        herds
+    }
+
+    pub fn to_grid(&self) -> Grid<char> {
+        let (rows, cols) = (self.east_cols.line_len, self.east_rows.line_len);
+        let mut cucumbers = Grid::new(rows, cols, EMPTY);
+        for (row, col) in cucumbers.positions().collect::<Vec<_>>() {
+            if self.east_rows.get(row, col) {
+                cucumbers[(row, col)] = EAST;
+            } else if self.south_rows.get(row, col) {
+                cucumbers[(row, col)] = SOUTH;
+            }
+        }
+        cucumbers
+    }
+
+    /// Same as 'step', but in place. Returns the number of cucumbers which moved.
+    pub fn step(&mut self) -> usize {
+        // As per the problem specification, East-facing sea cucumbers move first.
+        let moved_east = move_herd(&mut self.east_rows, &self.south_rows, &mut self.east_cols);
+        let moved_south = move_herd(&mut self.south_cols, &self.east_cols, &mut self.south_rows);
+        moved_east + moved_south
+    }
+}
+
+/// Returns the first step on which no sea cucumber moves, or 'None' if that does not happen within 'max_steps'.
+pub fn stop_step(cucumbers: &Grid<char>, max_steps: usize) -> Option<usize> {
+    let mut herds = Herds::from_grid(cucumbers);
+    (1..=max_steps).find(|_| herds.step() == 0)
+}
+
+/// Same as 'stop_step', but stepping a grid of chars, which is easier to trust than the bitboards.
+#[cfg(test)]
+fn stop_step_reference(cucumbers: &Grid<char>, max_steps: usize) -> Option<usize> {
+    let mut state = cucumbers.clone();
+    for step_idx in 0..max_steps {
+        let (new_state, n_moved) = step(&state);
+        state = new_state;
+        if n_moved == 0 {
+            return Some(step_idx + 1);
+        }
+    }
+    None
+}
+
+/// Generates a random grid where every cell holds a sea cucumber with probability 'density', facing east or south with
+/// equal odds. Handy for benchmarking on grids much larger than the puzzle input.
+pub fn generate(rows: usize, cols: usize, density: f64, seed: u64) -> Grid<char> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let cells = (0..rows * cols)
+        .map(|_| {
+            if !rng.gen_bool(density) {
+                EMPTY
+            } else if rng.gen_bool(0.5) {
+                EAST
+            } else {
+                SOUTH
+            }
+        })
+        .collect();
+    Grid::from_vec(rows, cols, cells)
+}
+
+/// State update which applies the rules of the sea cucumber simulation. Returns the new state and # of moved cucumbers.
+#[cfg(test)]
+fn step(cucumbers: &Grid<char>) -> (Grid<char>, usize) {
+    let mut moved_east: usize = 0;
+    let mut moved_south: usize = 0;
+
+    // NOTE(andrei): I can pre-allocate the intermediate buffers if further speed is necessary.
+    let mut next_state = Grid::new(cucumbers.rows(), cucumbers.cols(), EMPTY);
+
+    // As per the problem specification, East-facing sea cucumbers move first.
+    for pos in cucumbers.positions() {
+        if cucumbers[pos] == EAST {
+            let next_pos = cucumbers.wrapping_offset(pos, (0, 1));
+            if cucumbers[next_pos] == EMPTY {
+                next_state[next_pos] = EAST;
+                moved_east += 1;
+            } else {
+                next_state[pos] = EAST;
+            }
+        }
+    }
+
+    let mut next_next_state = Grid::new(cucumbers.rows(), cucumbers.cols(), EMPTY);
+
+    // As per the problem specification, South-facing sea cucumbers move second.
+    for pos in cucumbers.positions() {
+        if cucumbers[pos] == SOUTH {
+            let next_pos = cucumbers.wrapping_offset(pos, (1, 0));
+            if next_state[next_pos] == EMPTY && cucumbers[next_pos] != SOUTH {
+                next_next_state[next_pos] = SOUTH;
+                moved_south += 1;
+            } else {
+                next_next_state[pos] = SOUTH;
+            }
+        }
+    }
+    for pos in next_state.positions() {
+        if next_state[pos] == EAST {
+            next_next_state[pos] = EAST;
+        }
+    }
+
+    let moved_total = moved_east + moved_south;
+
+    (next_next_state, moved_total)
+}
+
+#[allow(dead_code)]
+fn print_cucumbers(cucumbers: &Grid<char>) {
+    println!("{}", cucumbers);
+}
+
+pub fn parse(input: &str) -> Grid<char> {
+    Grid::parse_chars(input)
+}
+
+pub fn solve(input: &str) -> (Answer, Answer) {
+    solve_parsed(&parse(input))
+}
+
+pub fn solve_parsed(data: &Grid<char>) -> (Answer, Answer) {
+    let part_1 = stop_step(data, MAX_STEPS).expect("The sea cucumbers never stopped moving.");
+    // There is no second puzzle on Christmas Day.
+    (part_1.into(), Answer::from("Merry Christmas!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bits(line: &[u64]) -> Vec<usize> {
+        (0..line.len() * WORD_BITS)
+            .filter(|&bit| (line[bit / WORD_BITS] >> (bit % WORD_BITS)) & 1 == 1)
+            .collect()
+    }
+
+    #[test]
+    fn test_rotate() {
+        for (line_len, set_bits, up, down) in [
+            (70, vec![0, 63, 69], vec![0, 1, 64], vec![62, 68, 69]),
+            (64, vec![0, 5, 63], vec![0, 1, 6], vec![4, 62, 63]),
+            (3, vec![2], vec![0], vec![1]),
+        ] {
+            let mut lines = BitLines::new(1, line_len);
+            for &bit in &set_bits {
+                lines.set(0, bit);
+            }
+            let mut out = vec![0; lines.words_per_line];
+            rotate_up(lines.line(0), line_len, lines.last_word_mask(), &mut out);
+            assert_eq!(bits(&out), up);
+            rotate_down(lines.line(0), line_len, &mut out);
+            assert_eq!(bits(&out), down);
+        }
+    }
+
+    #[test]
+    fn test_matches_reference() {
+        let demo = parse(
+            &std::fs::read_to_string(format!("{}/input/25-demo.txt", env!("CARGO_MANIFEST_DIR")))
+                .unwrap(),
+        );
+        let mut grids = vec![demo];
+        // Lines shorter than, exactly as long as, and longer than a word, as well as single rows and columns.
+        for (rows, cols) in [(1, 5), (5, 1), (3, 64), (9, 10), (70, 65), (129, 3)] {
+            for seed in 0..3 {
+                grids.push(generate(rows, cols, 0.3 + 0.2 * seed as f64, seed));
+            }
+        }
+
+        for grid in &grids {
+            let mut herds = Herds::from_grid(grid);
+            assert_eq!(&herds.to_grid(), grid);
+            let mut state = grid.clone();
+            for _ in 0..60 {
+                let (new_state, n_moved) = step(&state);
+                assert_eq!(herds.step(), n_moved);
+                assert_eq!(herds.to_grid(), new_state);
+                state = new_state;
+            }
+            assert_eq!(stop_step(grid, 200), stop_step_reference(grid, 200));
+        }
+        assert_eq!(stop_step(&grids[0], 200), Some(58));
+    }
+
+    #[test]
+    fn test_never_stops() {
+        let grid = parse(">..\n...\n");
+        assert_eq!(stop_step(&grid, 1000), None);
+        assert_eq!(stop_step_reference(&grid, 1000), None);
+    }
+
+    #[test]
+    fn test_large_generated() {
+        // Nearly five times as many cells as the puzzle input, which the char version takes a couple of seconds on,
+        // even in '--release' mode.
+        let grid = generate(300, 300, 0.5, 3);
+        assert_eq!(stop_step(&grid, MAX_STEPS), Some(1703));
+    }
+}
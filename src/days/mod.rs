@@ -0,0 +1,221 @@
+//! Registry of every solved day of the calendar.
+//!
+//! Each day lives in its own module and exposes a `solve(input: &str) -> (Answer, Answer)` entry point, which returns
+//! the answers to both parts of the puzzle without printing anything. Days which can reject an input, rather than
+//! panic on it, also expose `try_solve(input: &str) -> Result<(Answer, Answer), String>`. For benchmarking, the same
+//! work is also split into `parse(input: &str)` and `solve_parsed(&parsed)`, so the two phases can be timed separately.
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::answer::Answer;
+
+pub mod day01_depth_scanning;
+pub mod day02_dive;
+pub mod day03_diagnostic;
+pub mod day04_squid_bingo;
+pub mod day05_hydro_vents;
+pub mod day06_lanternfish;
+pub mod day07_crabs;
+pub mod day08_seven_segment;
+pub mod day09_smoke_basin;
+pub mod day10_syntax_scoring;
+pub mod day11_dumbo;
+pub mod day12_passage;
+pub mod day13_origami;
+pub mod day14_polymerization;
+pub mod day15_chiton;
+pub mod day16_packet_decoder;
+pub mod day17_trick_shot;
+pub mod day18_snailfish;
+pub mod day19_beacon_scanner;
+pub mod day20_trench_map;
+pub mod day21_dirac_dice;
+pub mod day22_reactor_reboot;
+pub mod day23_amphipod;
+pub mod day24_alu;
+pub mod day25_sea_cucumber;
+
+/// Solves both parts, or explains why the input has no answer.
+pub type Solver = fn(&str) -> Result<(Answer, Answer), String>;
+
+/// How long each phase of solving a day took.
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    /// Both parts are solved together, so this covers both of them.
+    pub solve: Duration,
+}
+
+pub struct Day {
+    pub number: u32,
+    pub title: &'static str,
+    /// Path to the puzzle input, relative to the repo root. 'None' for days whose input is still embedded in the code.
+    pub input: Option<&'static str>,
+    pub solve: Solver,
+    /// Parses and solves the given input once, timing each phase separately.
+    pub time_phases: fn(&str) -> PhaseTimes,
+}
+
+impl Day {
+    /// Reads the puzzle input from 'path', falling back to the day's default input if no path is given.
+    pub fn load_input(&self, path: Option<&Path>) -> io::Result<String> {
+        match (path, self.input) {
+            (Some(path), _) => fs::read_to_string(path),
+            (None, Some(default_path)) => fs::read_to_string(default_path),
+            (None, None) => Ok(String::new()),
+        }
+    }
+
+    /// Returns the path to the day's demo input: 'NN-demo.txt' if there is one, otherwise the first of the numbered
+    /// demos, like 'NN-demo-01.txt'.
+    pub fn demo_input(&self) -> Option<PathBuf> {
+        let single = PathBuf::from(format!("input/{:02}-demo.txt", self.number));
+        if single.exists() {
+            return Some(single);
+        }
+
+        let prefix = format!("{:02}-demo-", self.number);
+        let mut numbered: Vec<PathBuf> = fs::read_dir("input")
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+            .map(|entry| entry.path())
+            .collect();
+        numbered.sort();
+        numbered.into_iter().next()
+    }
+}
+
+fn time_phases<T>(
+    input: &str,
+    parse: impl Fn(&str) -> T,
+    solve: impl Fn(&T) -> (Answer, Answer),
+) -> PhaseTimes {
+    let start = Instant::now();
+    let parsed = black_box(parse(input));
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    black_box(solve(&parsed));
+    PhaseTimes {
+        parse: parse_time,
+        solve: start.elapsed(),
+    }
+}
+
+/// Builds the registry entry for a day module, which must expose 'parse', 'solve', and 'solve_parsed'. With 'try_solve',
+/// the day's 'try_solve' is used instead of 'solve', so that its errors reach the caller.
+macro_rules! day {
+    ($number:expr, $title:expr, $input:expr, $module:ident) => {
+        day!(@entry $number, $title, $input, $module, |input| Ok($module::solve(input)))
+    };
+    ($number:expr, $title:expr, $input:expr, $module:ident, try_solve) => {
+        day!(@entry $number, $title, $input, $module, $module::try_solve)
+    };
+    (@entry $number:expr, $title:expr, $input:expr, $module:ident, $solve:expr) => {
+        Day {
+            number: $number,
+            title: $title,
+            input: $input,
+            solve: $solve,
+            time_phases: |input| {
+                time_phases(input, $module::parse, |parsed| {
+                    $module::solve_parsed(parsed)
+                })
+            },
+        }
+    };
+}
+
+/// All the days with a working solver, in calendar order.
+pub const ALL: &[Day] = &[
+    day!(1, "Sonar Sweep", Some("input/01.txt"), day01_depth_scanning),
+    day!(2, "Dive!", Some("input/02.txt"), day02_dive),
+    day!(
+        3,
+        "Binary Diagnostic",
+        Some("input/03.txt"),
+        day03_diagnostic
+    ),
+    day!(4, "Giant Squid", Some("input/04.txt"), day04_squid_bingo),
+    day!(
+        5,
+        "Hydrothermal Venture",
+        Some("input/05.txt"),
+        day05_hydro_vents
+    ),
+    day!(6, "Lanternfish", Some("input/06.txt"), day06_lanternfish),
+    day!(
+        7,
+        "The Treachery of Whales",
+        Some("input/07.txt"),
+        day07_crabs
+    ),
+    day!(
+        8,
+        "Seven Segment Search",
+        Some("input/08.txt"),
+        day08_seven_segment
+    ),
+    day!(9, "Smoke Basin", Some("input/09.txt"), day09_smoke_basin),
+    day!(
+        10,
+        "Syntax Scoring",
+        Some("input/10.txt"),
+        day10_syntax_scoring
+    ),
+    day!(11, "Dumbo Octopus", Some("input/11.txt"), day11_dumbo),
+    day!(12, "Passage Pathing", Some("input/12.txt"), day12_passage),
+    day!(
+        13,
+        "Transparent Origami",
+        Some("input/13.txt"),
+        day13_origami
+    ),
+    day!(
+        14,
+        "Extended Polymerization",
+        Some("input/14.txt"),
+        day14_polymerization
+    ),
+    day!(15, "Chiton", Some("input/15.txt"), day15_chiton),
+    day!(
+        16,
+        "Packet Decoder",
+        Some("input/16.txt"),
+        day16_packet_decoder
+    ),
+    day!(
+        17,
+        "Trick Shot",
+        Some("input/17.txt"),
+        day17_trick_shot,
+        try_solve
+    ),
+    day!(18, "Snailfish", Some("input/18.txt"), day18_snailfish),
+    day!(
+        19,
+        "Beacon Scanner",
+        Some("input/19.txt"),
+        day19_beacon_scanner
+    ),
+    day!(20, "Trench Map", Some("input/20.txt"), day20_trench_map),
+    day!(21, "Dirac Dice", Some("input/21.txt"), day21_dirac_dice),
+    day!(
+        22,
+        "Reactor Reboot",
+        Some("input/22.txt"),
+        day22_reactor_reboot
+    ),
+    day!(23, "Amphipod", Some("input/23.txt"), day23_amphipod),
+    day!(24, "Arithmetic Logic Unit", Some("input/24.txt"), day24_alu),
+    day!(25, "Sea Cucumber", Some("input/25.txt"), day25_sea_cucumber),
+];
+
+/// Looks up the solver for the given day, if there is one.
+pub fn get(number: u32) -> Option<&'static Day> {
+    ALL.iter().find(|day| day.number == number)
+}
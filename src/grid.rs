@@ -0,0 +1,342 @@
+//! A contiguous, row-major 2D grid shared by all the puzzles played out on a map (days 09, 11, 15, 20, 25, ...).
+//!
+//! Positions are always (row, col) tuples. Neighbour iterators only yield in-bounds positions, so callers never have
+//! to hand-roll the bounds checks (or the signed/unsigned casts which go with them).
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A (row, col) position in a grid.
+pub type Pos = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a 'rows' x 'cols' grid with every cell set to 'fill'.
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self {
+        Grid {
+            rows,
+            cols,
+            data: vec![fill; rows * cols],
+        }
+    }
+
+    /// Returns the grid repeated 'factor' times in each direction.
+    ///
+    /// 'f' computes each cell of a copy from the original value and the (row, col) of the tile it lands in, e.g., to
+    /// increase the risk levels in the more distant tiles on Day 15.
+    pub fn tile<F>(&self, factor: usize, f: F) -> Self
+    where
+        F: Fn(&T, usize, usize) -> T,
+    {
+        let rows = self.rows * factor;
+        let cols = self.cols * factor;
+        let mut data = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                let original = &self[(row % self.rows, col % self.cols)];
+                data.push(f(original, row / self.rows, col / self.cols));
+            }
+        }
+        Grid { rows, cols, data }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from row-major 'data', which must have exactly 'rows' * 'cols' elements.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(
+            data.len(),
+            rows * cols,
+            "Grid data length does not match its {}x{} shape.",
+            rows,
+            cols
+        );
+        Grid { rows, cols, data }
+    }
+
+    /// Parses one cell per character, with one row per (non-empty) line of 'text'.
+    ///
+    /// Panics if the rows are not all the same length.
+    pub fn parse_with<F>(text: &str, f: F) -> Self
+    where
+        F: Fn(char) -> T,
+    {
+        let mut rows = 0;
+        let mut cols = 0;
+        let mut data = Vec::new();
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let n_before = data.len();
+            data.extend(line.chars().map(&f));
+            let row_len = data.len() - n_before;
+            if rows == 0 {
+                cols = row_len;
+            } else if row_len != cols {
+                panic!(
+                    "Ragged grid: row {} has {} cells, but row 0 has {}.",
+                    rows, row_len, cols
+                );
+            }
+            rows += 1;
+        }
+        Grid { rows, cols, data }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.data[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            Some(&mut self.data[row * self.cols + col])
+        } else {
+            None
+        }
+    }
+
+    /// Like 'get', but takes signed coordinates, so that positions off the top or left of the grid are simply None.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        if row < 0 || col < 0 {
+            None
+        } else {
+            self.get((row as usize, col as usize))
+        }
+    }
+
+    /// Iterates over all positions in row-major order.
+    ///
+    /// Does not borrow the grid, so it can be used to drive in-place updates.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |row| (0..cols).map(move |col| (row, col)))
+    }
+
+    /// Iterates over all cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.data.iter_mut()
+    }
+
+    /// Iterates over the rows of the grid as slices.
+    pub fn row_slices(&self) -> impl Iterator<Item = &[T]> {
+        // 'max(1)' keeps 'chunks' happy on degenerate 0-column grids, which have no data anyway.
+        self.data.chunks(self.cols.max(1))
+    }
+
+    /// The up-to-four in-bounds positions sharing an edge with 'pos' (up, down, left, right).
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let (rows, cols) = (self.rows, self.cols);
+        OFFSETS_4
+            .iter()
+            .filter_map(move |&delta| offset_in_bounds(pos, delta, rows, cols))
+    }
+
+    /// The up-to-eight in-bounds positions sharing an edge or a corner with 'pos'.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let (rows, cols) = (self.rows, self.cols);
+        OFFSETS_8
+            .iter()
+            .filter_map(move |&delta| offset_in_bounds(pos, delta, rows, cols))
+    }
+
+    /// Moves 'pos' by 'delta', wrapping around the edges as if the grid were a torus (e.g., for Day 25's currents).
+    pub fn wrapping_offset(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Pos {
+        (
+            (row as isize + d_row).rem_euclid(self.rows as isize) as usize,
+            (col as isize + d_col).rem_euclid(self.cols as isize) as usize,
+        )
+    }
+
+    /// The four positions sharing an edge with 'pos' on a toroidal grid.
+    pub fn wrapping_neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        OFFSETS_4
+            .iter()
+            .map(move |&delta| self.wrapping_offset(pos, delta))
+    }
+
+    /// Applies 'f' to every cell, keeping the shape.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders the grid one character per cell, one line per row, without a trailing newline.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        self.row_slices()
+            .map(|row| row.iter().map(&f).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a block of single-digit numbers, like the height maps of Day 09 or the octopi of Day 11.
+    pub fn parse_digits(text: &str) -> Self {
+        Grid::parse_with(text, |ch| {
+            ch.to_digit(10)
+                .unwrap_or_else(|| panic!("Invalid digit in grid: {:?}", ch))
+        })
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(text: &str) -> Self {
+        Grid::parse_with(text, |ch| ch)
+    }
+}
+
+fn offset_in_bounds(
+    (row, col): Pos,
+    (d_row, d_col): (isize, isize),
+    rows: usize,
+    cols: usize,
+) -> Option<Pos> {
+    let row = row.checked_add_signed(d_row)?;
+    let col = col.checked_add_signed(d_col)?;
+    if row < rows && col < cols {
+        Some((row, col))
+    } else {
+        None
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Position ({}, {}) is outside the {}x{} grid.",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Position ({}, {}) is outside the {}x{} grid.",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+/// Prints every cell back-to-back, one row per line, so a parsed grid of digits or chars round-trips to its input.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, row) in self.row_slices().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: &str = "123\n456\n";
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let grid = Grid::parse_digits(DIGITS);
+        assert_eq!(grid.rows(), 2);
+        assert_eq!(grid.cols(), 3);
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.to_string(), DIGITS.trim());
+
+        let chars = Grid::parse_chars("v.>\n>>.");
+        assert_eq!(chars.to_string(), "v.>\n>>.");
+    }
+
+    #[test]
+    #[should_panic(expected = "Ragged grid")]
+    fn test_parse_ragged() {
+        Grid::parse_digits("123\n45\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse_digits(DIGITS);
+        let corner: Vec<Pos> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::parse_digits(DIGITS);
+        assert_eq!(grid.wrapping_offset((0, 2), (0, 1)), (0, 0));
+        assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (1, 2));
+        let mut around: Vec<Pos> = grid.wrapping_neighbors4((0, 0)).collect();
+        around.sort_unstable();
+        assert_eq!(around, vec![(0, 1), (0, 2), (1, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn test_tile() {
+        let grid = Grid::parse_digits("8");
+        let tiled = grid.tile(3, |val, t_row, t_col| {
+            (val + (t_row + t_col) as u32 - 1) % 9 + 1
+        });
+        assert_eq!(tiled.to_string(), "891\n912\n123");
+    }
+}
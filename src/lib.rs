@@ -0,0 +1,9 @@
+//! Andrei's 2021 Advent of Code solutions, as a library shared by the `aoc21` runner.
+pub mod answer;
+pub mod bench;
+pub mod days;
+pub mod grid;
+pub mod verify;
+
+pub use answer::Answer;
+pub use grid::Grid;
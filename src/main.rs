@@ -0,0 +1,468 @@
+//! `aoc21`: a single runner for every day of Andrei's 2021 Advent of Code solutions.
+//!
+//! Examples (from the repo root, since input paths are relative to it):
+//!     cargo run --release -- run 19
+//!     cargo run --release -- run 3 --part 2 --input input/03-demo.txt
+//!     cargo run --release -- run 7 --demo
+//!     cargo run --release -- run --all
+//!     cargo run --release -- run --all --format json
+//!     cargo run --release -- run 23 --show-path
+//!     cargo run --release -- verify
+//!     cargo run --release -- bench --save baseline.json
+//!     cargo run --release -- bench 15 19 --baseline baseline.json
+//!     cargo run --release -- alu-search --threads 8
+//!     cargo run --release -- dirac-odds --weights 1,2,3
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use structopt::StructOpt;
+
+use aoc21::days::{self, Day};
+use aoc21::{bench, verify, Answer};
+
+#[derive(StructOpt)]
+#[structopt(name = "aoc21", about = "Andrei's 2021 Advent of Code solutions.")]
+enum Cli {
+    /// Solves a single day, or every day with '--all'.
+    Run(RunArgs),
+    /// Checks every day's answers against the ones recorded in 'answers.toml'.
+    Verify(VerifyArgs),
+    /// Times the parse and solve phases of every day (or just the given ones).
+    Bench(BenchArgs),
+    /// Solves Day 24 with the original brute-force search over the ALU's 'z' states, instead of the fast solver.
+    AluSearch(AluSearchArgs),
+    /// Compares Day 21's universe counts with the exact odds of winning, and with Monte-Carlo estimates of them.
+    DiracOdds(DiracOddsArgs),
+}
+
+#[derive(StructOpt)]
+struct RunArgs {
+    /// The day to solve (1-25).
+    #[structopt(required_unless = "all")]
+    day: Option<u32>,
+
+    /// Solves every day of the calendar, in order.
+    #[structopt(long, conflicts_with_all = &["day", "input", "demo"])]
+    all: bool,
+
+    /// Only reports the answer to this part of the puzzle.
+    #[structopt(long, possible_values = &["1", "2"])]
+    part: Option<u32>,
+
+    /// Reads the puzzle input from this file instead of the day's default input.
+    #[structopt(long, parse(from_os_str))]
+    input: Option<PathBuf>,
+
+    /// Solves the day's demo input (e.g., 'input/07-demo.txt') instead of the real one.
+    #[structopt(long, conflicts_with = "input")]
+    demo: bool,
+
+    /// 'text' for humans, or 'json' for one {day, part, answer, elapsed_us} record per line.
+    #[structopt(long, default_value = "text", possible_values = &["text", "json"])]
+    format: Format,
+
+    /// Also prints every move of the optimal solution. Only Day 23 supports this.
+    #[structopt(long, conflicts_with = "all")]
+    show_path: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        match raw {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            other => Err(format!("Unknown output format: {}", other)),
+        }
+    }
+}
+
+/// One line of the JSON output.
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u32,
+    part: u32,
+    answer: &'a Answer,
+    /// Both parts are solved in one go, so this is the time taken by the whole day, and is the same for both parts.
+    elapsed_us: u128,
+}
+
+#[derive(StructOpt)]
+struct VerifyArgs {
+    /// Only checks this day.
+    day: Option<u32>,
+
+    /// The file with the expected answers.
+    #[structopt(long, parse(from_os_str), default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(StructOpt)]
+struct BenchArgs {
+    /// The days to time. Defaults to all of them.
+    days: Vec<u32>,
+
+    /// How many times to solve each day.
+    #[structopt(long, default_value = "20")]
+    samples: usize,
+
+    /// Stops sampling a day after this many seconds, so that the slow days finish in reasonable time.
+    #[structopt(long, default_value = "10")]
+    budget_s: f64,
+
+    /// Saves the results as a JSON baseline.
+    #[structopt(long, parse(from_os_str))]
+    save: Option<PathBuf>,
+
+    /// Compares the results to a baseline saved earlier with '--save'.
+    #[structopt(long, parse(from_os_str))]
+    baseline: Option<PathBuf>,
+}
+
+#[derive(StructOpt)]
+struct AluSearchArgs {
+    /// The ALU program to search.
+    #[structopt(long, parse(from_os_str), default_value = "input/24.txt")]
+    input: PathBuf,
+
+    /// How many worker threads to split every digit's 'z' states across. Defaults to one per CPU.
+    #[structopt(long)]
+    threads: Option<usize>,
+}
+
+#[derive(StructOpt)]
+struct DiracOddsArgs {
+    /// The starting positions.
+    #[structopt(long, parse(from_os_str), default_value = "input/21.txt")]
+    input: PathBuf,
+
+    /// How many games to simulate.
+    #[structopt(long, default_value = "100000")]
+    games: usize,
+
+    /// Seeds the die, so that simulations can be reproduced.
+    #[structopt(long, default_value = "0")]
+    seed: u64,
+
+    /// Loads the die: side 'i' comes up with a probability proportional to the i-th weight, and the die gets one side per
+    /// weight. Defaults to the puzzle's fair 3-sided die.
+    #[structopt(long, use_delimiter = true)]
+    weights: Vec<u64>,
+}
+
+fn print_answer(part: u32, answer: &Answer) {
+    let text = answer.to_string();
+    if text.contains('\n') {
+        // Multi-line answers, like Day 13's, read better starting on their own line.
+        println!("  Part {}:", part);
+        for line in text.lines() {
+            println!("    {}", line);
+        }
+    } else {
+        println!("  Part {}: {}", part, text);
+    }
+}
+
+fn print_record(day: u32, part: u32, answer: &Answer, elapsed: Duration) {
+    let record = Record {
+        day,
+        part,
+        answer,
+        elapsed_us: elapsed.as_micros(),
+    };
+    println!(
+        "{}",
+        serde_json::to_string(&record).expect("Answers always serialize to JSON.")
+    );
+}
+
+fn run_day(
+    day: &Day,
+    input_path: Option<&Path>,
+    part: Option<u32>,
+    format: Format,
+) -> Result<(), String> {
+    let input = day.load_input(input_path).map_err(|err| {
+        let path = input_path
+            .map(Path::to_path_buf)
+            .or_else(|| day.input.map(PathBuf::from));
+        format!(
+            "Day {:02}: unable to read {:?}: {}",
+            day.number,
+            path.unwrap_or_default(),
+            err
+        )
+    })?;
+
+    let start = Instant::now();
+    let (part_1, part_2) =
+        (day.solve)(&input).map_err(|err| format!("Day {:02}: {}", day.number, err))?;
+    let elapsed = start.elapsed();
+
+    if format == Format::Text {
+        println!("Day {:02}: {} ({:.2?})", day.number, day.title, elapsed);
+    }
+    for (part_idx, answer) in [(1, &part_1), (2, &part_2)] {
+        if part.is_some_and(|part| part != part_idx) {
+            continue;
+        }
+        match format {
+            Format::Text => print_answer(part_idx, answer),
+            Format::Json => print_record(day.number, part_idx, answer, elapsed),
+        }
+    }
+    Ok(())
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    if args.all {
+        for day in days::ALL {
+            run_day(day, None, args.part, args.format)?;
+        }
+        return Ok(());
+    }
+
+    let number = args
+        .day
+        .expect("structopt enforces a day unless '--all' is given");
+    let day = days::get(number).ok_or(format!("Day {} has no solver (yet).", number))?;
+    if args.show_path && number != 23 {
+        return Err(format!("Day {:02} has no path to show.", number));
+    }
+    let input = if args.demo {
+        Some(
+            day.demo_input()
+                .ok_or(format!("Day {:02} has no demo input.", number))?,
+        )
+    } else {
+        args.input.clone()
+    };
+    run_day(day, input.as_deref(), args.part, args.format)?;
+
+    if args.show_path {
+        let raw = day
+            .load_input(input.as_deref())
+            .map_err(|err| format!("Day {:02}: unable to read its input: {}", number, err))?;
+        days::day23_amphipod::show_path(&raw);
+    }
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let entries = verify::load(&args.answers)?;
+    // Inputs are listed relative to the repo root, which is where the answers file lives.
+    let root = args.answers.parent().unwrap_or_else(|| Path::new(""));
+
+    let mut n_checked = 0;
+    let mut n_failed = 0;
+    for entry in &entries {
+        if args.day.is_some_and(|day| day != entry.day) {
+            continue;
+        }
+
+        n_checked += 1;
+        match entry.check(root) {
+            Ok(mismatches) if mismatches.is_empty() => {
+                println!("ok      Day {:02} {}", entry.day, entry.input_name());
+            }
+            Ok(mismatches) => {
+                n_failed += 1;
+                println!("FAILED  Day {:02} {}", entry.day, entry.input_name());
+                for mismatch in mismatches {
+                    println!("          {}", mismatch);
+                }
+            }
+            Err(message) => {
+                n_failed += 1;
+                println!(
+                    "ERROR   Day {:02} {}: {}",
+                    entry.day,
+                    entry.input_name(),
+                    message
+                );
+            }
+        }
+    }
+
+    println!("{} checked, {} failed.", n_checked, n_failed);
+    if n_failed > 0 {
+        Err(format!("{} of {} checks failed.", n_failed, n_checked))
+    } else {
+        Ok(())
+    }
+}
+
+fn format_us(micros: f64) -> String {
+    format!("{:.2?}", Duration::from_secs_f64(micros / 1e6))
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let baseline: Vec<bench::Record> = match &args.baseline {
+        Some(path) => {
+            let raw = fs::read_to_string(path)
+                .map_err(|err| format!("Unable to read {:?}: {}", path, err))?;
+            serde_json::from_str(&raw)
+                .map_err(|err| format!("Invalid baseline {:?}: {}", path, err))?
+        }
+        None => Vec::new(),
+    };
+
+    let to_run: Vec<&Day> = if args.days.is_empty() {
+        days::ALL.iter().collect()
+    } else {
+        args.days
+            .iter()
+            .map(|&number| days::get(number).ok_or(format!("Day {} has no solver (yet).", number)))
+            .collect::<Result<_, _>>()?
+    };
+
+    println!(
+        "{:>3}  {:<5}  {:>7}  {:>12}  {:>12}  {:>9}",
+        "Day", "Phase", "Samples", "Median", "P95", "vs. base"
+    );
+    let mut records = Vec::new();
+    for day in to_run {
+        let input = day
+            .load_input(None)
+            .map_err(|err| format!("Day {:02}: unable to read its input: {}", day.number, err))?;
+        let budget = Duration::from_secs_f64(args.budget_s);
+        for record in bench::bench_day(day, &input, args.samples, budget) {
+            let change = bench::find_baseline(&baseline, &record)
+                .map(|old| format!("{:+.1}%", (record.median_us / old.median_us - 1.0) * 100.0))
+                .unwrap_or_default();
+            println!(
+                "{:>3}  {:<5}  {:>7}  {:>12}  {:>12}  {:>9}",
+                record.day,
+                record.phase,
+                record.samples,
+                format_us(record.median_us),
+                format_us(record.p95_us),
+                change
+            );
+            records.push(record);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        let raw =
+            serde_json::to_string_pretty(&records).expect("Records always serialize to JSON.");
+        fs::write(path, raw).map_err(|err| format!("Unable to write {:?}: {}", path, err))?;
+    }
+    Ok(())
+}
+
+fn alu_search(args: &AluSearchArgs) -> Result<(), String> {
+    let raw = fs::read_to_string(&args.input)
+        .map_err(|err| format!("Unable to read {:?}: {}", args.input, err))?;
+    let program: days::day24_alu::Program = raw
+        .parse()
+        .map_err(|err| format!("Invalid ALU program {:?}: {}", args.input, err))?;
+    let threads = match args.threads {
+        Some(0) => return Err("Need at least one thread.".to_string()),
+        Some(threads) => threads,
+        None => std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+    };
+
+    let start = Instant::now();
+    let (largest, smallest) =
+        days::day24_alu::solve_version_a(&program.instructions, threads, true)?;
+    let elapsed = start.elapsed();
+
+    let (largest, smallest) = largest
+        .zip(smallest)
+        .ok_or("No model number is accepted by the program.")?;
+    println!("Day 24 search with {} thread(s) ({:.2?})", threads, elapsed);
+    print_answer(1, &largest.into());
+    print_answer(2, &smallest.into());
+    Ok(())
+}
+
+fn dirac_odds(args: &DiracOddsArgs) -> Result<(), String> {
+    use days::day21_dirac_dice::{
+        self, monte_carlo, DiracGame, RandomDie, WeightedDie, PART_2_GAME,
+    };
+
+    let raw = fs::read_to_string(&args.input)
+        .map_err(|err| format!("Unable to read {:?}: {}", args.input, err))?;
+    let (start_1, start_2) = day21_dirac_dice::try_parse(&raw)
+        .map_err(|err| format!("Invalid starting positions {:?}: {}", args.input, err))?;
+    let starts = [start_1, start_2];
+    if args.games == 0 {
+        return Err("Need at least one game.".to_string());
+    }
+
+    // The universes split once per side of the die, so the exact counts need as many sides as there are weights.
+    let game = DiracGame {
+        die_sides: if args.weights.is_empty() {
+            PART_2_GAME.die_sides
+        } else {
+            args.weights.len()
+        },
+        ..PART_2_GAME
+    };
+    let universes = game.try_count_wins(&starts).ok_or(format!(
+        "Too many universes to count with a {}-sided die.",
+        game.die_sides
+    ))?;
+    let (exact, estimates) = if args.weights.is_empty() {
+        let die = RandomDie::new(game.die_sides, args.seed);
+        let exact = game.win_probabilities(&starts, &die);
+        (
+            exact,
+            monte_carlo(&game, &starts, Rc::new(RefCell::new(die)), args.games),
+        )
+    } else {
+        let die = WeightedDie::new(&args.weights, args.seed)?;
+        let exact = game.win_probabilities(&starts, &die);
+        (
+            exact,
+            monte_carlo(&game, &starts, Rc::new(RefCell::new(die)), args.games),
+        )
+    };
+
+    println!(
+        "Day 21 odds from starts {:?} with a {}-sided die, over {} simulated games",
+        starts, game.die_sides, args.games
+    );
+    println!("  Player  {:<39}  Exact odds  Simulated", "Universes won");
+    for (player, ((universes, exact), estimate)) in
+        universes.iter().zip(&exact).zip(&estimates).enumerate()
+    {
+        println!(
+            "  {:<6}  {:<39}  {:<10.4}  {:.4}",
+            player + 1,
+            universes,
+            exact,
+            estimate
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    let result = match Cli::from_args() {
+        Cli::Run(args) => run(&args),
+        Cli::Verify(args) => verify(&args),
+        Cli::Bench(args) => bench(&args),
+        Cli::AluSearch(args) => alu_search(&args),
+        Cli::DiracOdds(args) => dirac_odds(&args),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,119 @@
+//! Checks the solvers against the known-good answers recorded in 'answers.toml', so that refactoring a day cannot
+//! silently break it.
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::days;
+
+/// The expected answers for one (day, input file) combination.
+#[derive(Debug, Deserialize)]
+pub struct Expected {
+    pub day: u32,
+    /// Path to the input, relative to the repo root. 'None' for days whose input is still embedded in the code.
+    pub input: Option<String>,
+    pub part_1: Option<toml::Value>,
+    pub part_2: Option<toml::Value>,
+}
+
+#[derive(Deserialize)]
+struct AnswersFile {
+    answer: Vec<Expected>,
+}
+
+/// A single wrong answer.
+#[derive(Debug)]
+pub struct Mismatch {
+    pub part: u32,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "part {}: expected {:?}, got {:?}",
+            self.part, self.expected, self.actual
+        )
+    }
+}
+
+impl Expected {
+    /// A human-readable name for the checked input, e.g., "input/13-demo.txt".
+    pub fn input_name(&self) -> &str {
+        self.input.as_deref().unwrap_or("<embedded input>")
+    }
+
+    /// Runs the day's solver on the input (resolved relative to 'root') and returns every part which does not match.
+    ///
+    /// Errors out if the day is not registered, the input cannot be read or has no answer, or an answer in the file is
+    /// malformed.
+    pub fn check(&self, root: &Path) -> Result<Vec<Mismatch>, String> {
+        let day = days::get(self.day).ok_or(format!("Day {} has no solver.", self.day))?;
+        let input_path = self.input.as_ref().map(|input| root.join(input));
+        let input = day
+            .load_input(input_path.as_deref())
+            .map_err(|err| format!("Unable to read {}: {}", self.input_name(), err))?;
+
+        let (part_1, part_2) = (day.solve)(&input)?;
+
+        let mut mismatches = Vec::new();
+        for (part, expected, actual) in [(1, &self.part_1, part_1), (2, &self.part_2, part_2)] {
+            if let Some(expected) = expected {
+                let expected = to_answer(expected)?;
+                if !answers_match(&expected, &actual) {
+                    mismatches.push(Mismatch {
+                        part,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+/// Loads every expected answer from an 'answers.toml' file.
+pub fn load(path: &Path) -> Result<Vec<Expected>, String> {
+    let raw =
+        fs::read_to_string(path).map_err(|err| format!("Unable to read {:?}: {}", path, err))?;
+    let parsed: AnswersFile =
+        toml::from_str(&raw).map_err(|err| format!("Invalid answers file {:?}: {}", path, err))?;
+    Ok(parsed.answer)
+}
+
+fn to_answer(value: &toml::Value) -> Result<Answer, String> {
+    match value {
+        toml::Value::Integer(val) => Ok(Answer::Int(*val)),
+        toml::Value::String(text) => Ok(Answer::Text(text.clone())),
+        other => Err(format!(
+            "Answers must be integers or strings, got: {:?}",
+            other
+        )),
+    }
+}
+
+/// Trailing whitespace is noise in text answers: it is invisible, and multi-line TOML strings end with a newline.
+///
+/// Glyphs are recorded in their rendered form, so they are compared the same way.
+fn normalize(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    lines
+}
+
+fn answers_match(expected: &Answer, actual: &Answer) -> bool {
+    match (expected, actual) {
+        (Answer::Text(expected), Answer::Text(_) | Answer::Glyphs(_)) => {
+            normalize(expected) == normalize(&actual.render())
+        }
+        _ => expected == actual,
+    }
+}
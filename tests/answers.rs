@@ -0,0 +1,83 @@
+//! Runs every day's solver against the answers recorded in 'answers.toml'.
+use std::fs;
+use std::path::Path;
+
+use aoc21::days;
+use aoc21::verify::{self, Expected};
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn load_answers() -> Vec<Expected> {
+    verify::load(&root().join("answers.toml")).unwrap()
+}
+
+/// Checks all the entries, and panics with a list of all failures, if any.
+#[test]
+fn test_answers() {
+    let mut failures = Vec::new();
+    for entry in &load_answers() {
+        match entry.check(root()) {
+            Ok(mismatches) => {
+                for mismatch in mismatches {
+                    failures.push(format!(
+                        "Day {:02} {}: {}",
+                        entry.day,
+                        entry.input_name(),
+                        mismatch
+                    ));
+                }
+            }
+            Err(message) => failures.push(format!(
+                "Day {:02} {}: {}",
+                entry.day,
+                entry.input_name(),
+                message
+            )),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "Wrong answers:\n{}",
+        failures.join("\n")
+    );
+}
+
+/// Makes sure nobody adds a day or a demo file without also recording its answers.
+#[test]
+fn test_every_input_has_answers() {
+    let answers = load_answers();
+
+    for day in days::ALL {
+        assert!(
+            answers.iter().any(|entry| entry.day == day.number),
+            "Day {:02} has no entry in answers.toml.",
+            day.number
+        );
+    }
+
+    for dir_entry in fs::read_dir(root().join("input")).unwrap() {
+        let file_name = dir_entry.unwrap().file_name().into_string().unwrap();
+        let day_number: u32 = match file_name.get(..2).and_then(|prefix| prefix.parse().ok()) {
+            Some(number) => number,
+            None => continue,
+        };
+        // Inputs in other formats (e.g., Day 24's commented program) are not meant to be solved directly.
+        let is_puzzle_input =
+            file_name == format!("{:02}.txt", day_number) || file_name.contains("-demo");
+        if days::get(day_number).is_none() || !is_puzzle_input {
+            continue;
+        }
+
+        let input = format!("input/{}", file_name);
+        assert!(
+            answers
+                .iter()
+                .any(|entry| entry.input.as_deref() == Some(input.as_str())),
+            "{} has no entry in answers.toml.",
+            input
+        );
+    }
+}